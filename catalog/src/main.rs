@@ -0,0 +1,119 @@
+//! Lists the errors of a crate deriving the responses of [actix-web-error](https://docs.rs/actix-web-error).
+//!
+//! ```text
+//! actix-web-error-catalog [--format markdown|json|csv|typescript] [PATH...]
+//! ```
+//!
+//! Each `PATH` is a Rust source file or a directory (scanned recursively).
+//! For a crate directory (containing a `Cargo.toml`), only `src/` is scanned.
+//! Without a `PATH`, the current directory is scanned.
+
+#![warn(clippy::pedantic)]
+
+mod output;
+mod scan;
+
+use actix_web_error_internals::{attr, input};
+use output::Format;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str =
+    "Usage: actix-web-error-catalog [--format markdown|json|csv|typescript] [PATH...]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut format = Format::Markdown;
+    let mut paths = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            "-f" | "--format" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing format\n{USAGE}"))?;
+                format = value.parse()?;
+            }
+            _ => match arg.strip_prefix("--format=") {
+                Some(value) => format = value.parse()?,
+                None if arg.starts_with('-') => {
+                    return Err(format!("unknown option '{arg}'\n{USAGE}"))
+                }
+                None => paths.push(PathBuf::from(arg)),
+            },
+        }
+    }
+    if paths.is_empty() {
+        paths.push(PathBuf::from("."));
+    }
+
+    let mut entries = Vec::new();
+    for path in &paths {
+        let root = source_root(path);
+        let mut files = Vec::new();
+        collect_files(&root, &mut files)?;
+        for file in &files {
+            scan::scan_file(&root, file, &mut entries)?;
+        }
+    }
+
+    print!("{}", format.render(&entries));
+    Ok(())
+}
+
+/// `src/` for crate directories, the path itself otherwise.
+fn source_root(path: &Path) -> PathBuf {
+    let src = path.join("src");
+    if path.join("Cargo.toml").is_file() && src.is_dir() {
+        src
+    } else {
+        path.to_owned()
+    }
+}
+
+/// Collects `.rs` files in a stable order.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        if !path.exists() {
+            return Err(format!("{}: no such file or directory", path.display()));
+        }
+        files.push(path.to_owned());
+        return Ok(());
+    }
+
+    let mut children = fs::read_dir(path)
+        .and_then(|dir| {
+            dir.map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    children.sort();
+    for child in children {
+        if child.is_dir() {
+            if child.file_name().is_some_and(|name| name == "target") {
+                continue;
+            }
+            collect_files(&child, files)?;
+        } else if child.extension().is_some_and(|ext| ext == "rs") {
+            files.push(child);
+        }
+    }
+    Ok(())
+}
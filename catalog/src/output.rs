@@ -0,0 +1,203 @@
+use crate::scan::{Body, Entry};
+use std::{fmt::Write, str::FromStr};
+
+const HEADER: [&str; 6] = [
+    "Type",
+    "Variant",
+    "Status",
+    "Error Code",
+    "Message",
+    "Description",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Json,
+    Csv,
+    TypeScript,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "typescript" | "ts" => Ok(Self::TypeScript),
+            _ => Err(format!(
+                "unknown format '{s}', expected one of markdown, json, csv, typescript"
+            )),
+        }
+    }
+}
+
+impl Format {
+    pub fn render(self, entries: &[Entry]) -> String {
+        match self {
+            Self::Markdown => markdown(entries),
+            Self::Json => {
+                let mut out =
+                    serde_json::to_string_pretty(entries).expect("entries are valid json");
+                out.push('\n');
+                out
+            }
+            Self::Csv => csv(entries),
+            Self::TypeScript => typescript(entries),
+        }
+    }
+}
+
+fn columns(entry: &Entry) -> [&str; 6] {
+    [
+        &entry.ty,
+        entry.variant.as_deref().unwrap_or_default(),
+        &entry.status,
+        entry.error_code.as_deref().unwrap_or_default(),
+        entry.message.as_deref().unwrap_or_default(),
+        entry.doc.as_deref().unwrap_or_default(),
+    ]
+}
+
+fn markdown(entries: &[Entry]) -> String {
+    let mut out = format!("| {} |\n", HEADER.join(" | "));
+    let _ = writeln!(out, "|{}", " --- |".repeat(HEADER.len()));
+    for entry in entries {
+        out.push('|');
+        for column in columns(entry) {
+            let column = column.replace('|', "\\|");
+            if column.is_empty() {
+                out.push_str(" |");
+            } else {
+                let _ = write!(out, " {column} |");
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn csv(entries: &[Entry]) -> String {
+    let mut out = format!("{}\r\n", HEADER.join(","));
+    for entry in entries {
+        let row: Vec<_> = columns(entry).into_iter().map(csv_field).collect();
+        out.push_str(&row.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Type definitions for the JSON bodies: a union of the error codes and a body type per error type.
+///
+/// Error codes given as expressions can't be resolved, so the union of such a type is widened to `string`.
+fn typescript(entries: &[Entry]) -> String {
+    let mut out = String::from(
+        "\
+// Generated by actix-web-error-catalog. Do not edit.
+
+/** Body of a JSON error response. */
+export interface ErrorBody<Code extends string = string> {
+  message: string;
+  code?: Code;
+}
+",
+    );
+
+    // types of different modules may share a name
+    let mut types: Vec<((&str, &str), Vec<&Entry>)> = Vec::new();
+    for entry in entries.iter().filter(|entry| entry.body == Body::Json) {
+        let key = (entry.module.as_str(), entry.ty.as_str());
+        match types.iter_mut().find(|(ty, _)| *ty == key) {
+            Some((_, variants)) => variants.push(entry),
+            None => types.push((key, vec![entry])),
+        }
+    }
+    let keys: Vec<_> = types.iter().map(|(ty, _)| *ty).collect();
+    let names = type_names(&keys);
+
+    for (((module, ty), variants), name) in types.into_iter().zip(names) {
+        let path = if module.is_empty() {
+            ty.to_owned()
+        } else {
+            format!("{module}::{ty}")
+        };
+        let mut codes: Vec<String> = Vec::new();
+        for entry in &variants {
+            let code = match &entry.error_code {
+                _ if entry.dynamic_code => "string".to_owned(),
+                Some(code) => serde_json::to_string(code).expect("strings are valid json"),
+                None => continue,
+            };
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        let union = if codes.iter().any(|code| code == "string") {
+            "string".to_owned()
+        } else if codes.is_empty() {
+            "never".to_owned()
+        } else {
+            codes.join(" | ")
+        };
+
+        let _ = write!(
+            out,
+            "
+/** Error codes of `{path}`. */
+export type {name}Code = {union};
+/** Body of `{path}` responses. */
+export type {name}Body = ErrorBody<{name}Code>;
+"
+        );
+    }
+    out
+}
+
+/// Names of the exported types: the type name if it's unique,
+/// prefixed with its module (`api::users::Error` -> `ApiUsersError`) otherwise.
+///
+/// Names that still clash (e.g. types in the root of different crates) get a numeric suffix.
+fn type_names(types: &[(&str, &str)]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (module, ty) in types {
+        let shared = types.iter().filter(|(_, other)| other == ty).count() > 1;
+        let base = if shared {
+            module.split("::").map(pascal_case).collect::<String>() + ty
+        } else {
+            (*ty).to_owned()
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while names.contains(&name) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// `user_store` -> `UserStore`
+fn pascal_case(segment: &str) -> String {
+    segment
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
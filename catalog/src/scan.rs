@@ -0,0 +1,249 @@
+use crate::{
+    attr::{Attrs, Code, ErrorCode, ResolveStatus, StatusCode},
+    input::Input,
+};
+use serde::Serialize;
+use std::{fs, path::Path};
+use syn::{punctuated::Punctuated, Attribute, Data, DeriveInput, Item, Lit, Meta, Token};
+
+/// Derives of actix-web-error and the body they produce.
+const DERIVES: &[(&str, Body)] = &[
+    ("Json", Body::Json),
+    ("Text", Body::Text),
+    ("Html", Body::Html),
+    ("Xml", Body::Xml),
+    ("JsonApi", Body::JsonApi),
+    ("GoogleJson", Body::GoogleJson),
+    ("MsgPack", Body::MsgPack),
+    ("Cbor", Body::Cbor),
+    ("AxumJson", Body::Json),
+    ("AxumText", Body::Text),
+    ("HttpJson", Body::Json),
+    ("HttpText", Body::Text),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Body {
+    Json,
+    Text,
+    Html,
+    Xml,
+    JsonApi,
+    GoogleJson,
+    MsgPack,
+    Cbor,
+}
+
+/// A struct or a variant of an enum deriving an error response.
+#[derive(Debug, Serialize)]
+pub struct Entry {
+    /// Path of the module relative to the scanned directory, e.g. `api::users`.
+    #[serde(skip)]
+    pub module: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub variant: Option<String>,
+    pub status: String,
+    pub error_code: Option<String>,
+    pub message: Option<String>,
+    pub doc: Option<String>,
+    #[serde(skip)]
+    pub body: Body,
+    /// The error code is an expression, so its value isn't known.
+    #[serde(skip)]
+    pub dynamic_code: bool,
+}
+
+/// Scans a Rust source file below `root` for errors.
+pub fn scan_file(root: &Path, path: &Path, entries: &mut Vec<Entry>) -> Result<(), String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let file = syn::parse_file(&content).map_err(|e| format!("{}: {e}", path.display()))?;
+    let mut module = file_module(root, path);
+    scan_items(&file.items, &mut module, entries).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// `api/users.rs` -> `api::users`, `api/mod.rs` -> `api`
+fn file_module(root: &Path, path: &Path) -> Vec<String> {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return Vec::new();
+    };
+    let mut module: Vec<_> = relative
+        .with_extension("")
+        .iter()
+        .map(|segment| segment.to_string_lossy().into_owned())
+        .collect();
+    if module
+        .last()
+        .is_some_and(|last| matches!(last.as_str(), "mod" | "lib" | "main"))
+    {
+        module.pop();
+    }
+    module
+}
+
+fn scan_items(
+    items: &[Item],
+    module: &mut Vec<String>,
+    entries: &mut Vec<Entry>,
+) -> syn::Result<()> {
+    for item in items {
+        match item {
+            Item::Struct(item) => {
+                if let Some(body) = derived_body(&item.attrs) {
+                    scan_input(&item.clone().into(), &module.join("::"), body, entries)?;
+                }
+            }
+            Item::Enum(item) => {
+                if let Some(body) = derived_body(&item.attrs) {
+                    scan_input(&item.clone().into(), &module.join("::"), body, entries)?;
+                }
+            }
+            Item::Mod(item) => {
+                if let Some((_, items)) = &item.content {
+                    module.push(item.ident.to_string());
+                    scan_items(items, module, entries)?;
+                    module.pop();
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn scan_input(
+    node: &DeriveInput,
+    module: &str,
+    body: Body,
+    entries: &mut Vec<Entry>,
+) -> syn::Result<()> {
+    let ty = node.ident.to_string();
+    match Input::from_syn(node)? {
+        Input::Struct(input) => {
+            entries.push(entry(module, ty, None, body, &input.attrs, &node.attrs));
+        }
+        Input::Enum(input) => {
+            let Data::Enum(data) = &node.data else {
+                unreachable!("enums are parsed from enums")
+            };
+            for (variant, node) in input.variants.iter().zip(&data.variants) {
+                entries.push(entry(
+                    module,
+                    ty.clone(),
+                    Some(variant.ident.to_string()),
+                    body,
+                    &variant.attrs,
+                    &node.attrs,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn entry(
+    module: &str,
+    ty: String,
+    variant: Option<String>,
+    body: Body,
+    attrs: &Attrs,
+    original: &[Attribute],
+) -> Entry {
+    Entry {
+        module: module.to_owned(),
+        ty,
+        variant,
+        status: match &attrs.status {
+            Some(ResolveStatus::Fixed(status)) => status_name(&status.code),
+            Some(ResolveStatus::Transparent(_)) => "transparent".to_owned(),
+            Some(ResolveStatus::Source(_)) => "source".to_owned(),
+            // `ResponseError`'s default
+            None => "500".to_owned(),
+        },
+        error_code: attrs
+            .error_code
+            .as_ref()
+            .map(|code| match &code.error_code {
+                Code::String(code) => code.clone(),
+                Code::Name(group) => group.stream().to_string(),
+                Code::Transparent(_) => "transparent".to_owned(),
+            }),
+        message: attrs.display.as_ref().map(syn::LitStr::value),
+        doc: doc_comment(original),
+        body,
+        dynamic_code: matches!(
+            attrs.error_code,
+            Some(ErrorCode {
+                error_code: Code::Name(_) | Code::Transparent(_),
+                ..
+            })
+        ),
+    }
+}
+
+fn derived_body(attrs: &[Attribute]) -> Option<Body> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|path| {
+            let ident = &path.segments.last()?.ident;
+            DERIVES
+                .iter()
+                .find(|(derive, _)| ident == derive)
+                .map(|(_, body)| *body)
+        })
+}
+
+/// Numeric status for `StatusCode::NAME`s, the name otherwise.
+fn status_name(code: &StatusCode) -> String {
+    match code {
+        StatusCode::Value(value) => value.as_u16().to_string(),
+        StatusCode::Name(ident) => {
+            let name = ident.to_string();
+            (100..1000)
+                .filter_map(|code| http::StatusCode::from_u16(code).ok())
+                .find(|code| {
+                    code.canonical_reason()
+                        .is_some_and(|reason| constant_name(reason) == name)
+                })
+                .map_or(name, |code| code.as_u16().to_string())
+        }
+    }
+}
+
+/// `I'm a teapot` -> `IM_A_TEAPOT`
+fn constant_name(reason: &str) -> String {
+    reason
+        .chars()
+        .filter_map(|c| match c {
+            ' ' | '-' => Some('_'),
+            c if c.is_ascii_alphanumeric() => Some(c.to_ascii_uppercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(meta)) => match meta.lit {
+                Lit::Str(doc) => Some(doc.value().trim().to_owned()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
@@ -0,0 +1,19 @@
+const CODE: &str = "dynamic";
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+pub enum DynamicError {
+    #[error("static")]
+    #[error_code("static")]
+    Static,
+    #[error("dynamic")]
+    #[error_code((Some(CODE)))]
+    Dynamic,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("no code")]
+pub struct NoCode;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("text")]
+pub struct TextError;
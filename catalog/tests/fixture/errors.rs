@@ -0,0 +1,37 @@
+use actix_web_error::Json;
+
+/// The user doesn't exist.
+#[derive(Debug, thiserror::Error, Json)]
+#[error("User {0} not found")]
+#[status(NOT_FOUND)]
+#[error_code("user_not_found")]
+pub struct UserNotFound(u32);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+pub enum ApiError {
+    /// The request body is invalid,
+    /// see the message for details.
+    #[error("Invalid body: {0}")]
+    #[status(400)]
+    #[error_code("invalid_body")]
+    InvalidBody(String),
+    #[error("No | access")]
+    #[status(IM_A_TEAPOT)]
+    Teapot,
+    #[error(transparent)]
+    #[status(transparent)]
+    User(#[from] UserNotFound),
+    #[error("Internal error, \"sorry\"")]
+    Internal,
+}
+
+mod nested {
+    #[derive(Debug, thiserror::Error, actix_web_error::Text)]
+    #[error("Too many requests")]
+    #[status(429)]
+    pub struct RateLimited;
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("not derived")]
+pub struct Ignored;
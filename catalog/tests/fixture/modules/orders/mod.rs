@@ -0,0 +1,6 @@
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+pub enum Error {
+    #[error("Order not found")]
+    #[error_code("order_not_found")]
+    NotFound,
+}
@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+pub enum Error {
+    #[error("User not found")]
+    #[error_code("user_not_found")]
+    NotFound,
+}
+
+pub mod session {
+    #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+    #[error("Session expired")]
+    #[error_code("session_expired")]
+    pub struct Expired;
+}
@@ -0,0 +1,135 @@
+use std::process::Command;
+
+fn catalog(args: &[&str]) -> String {
+    catalog_of("errors.rs", args)
+}
+
+fn catalog_of(fixture: &str, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_actix-web-error-catalog"))
+        .args(args)
+        .arg(format!(
+            "{}/tests/fixture/{fixture}",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        catalog(&[]),
+        "\
+| Type | Variant | Status | Error Code | Message | Description |
+| --- | --- | --- | --- | --- | --- |
+| UserNotFound | | 404 | user_not_found | User {0} not found | The user doesn't exist. |
+| ApiError | InvalidBody | 400 | invalid_body | Invalid body: {0} | The request body is invalid, see the message for details. |
+| ApiError | Teapot | 418 | | No \\| access | |
+| ApiError | User | transparent | | | |
+| ApiError | Internal | 500 | | Internal error, \"sorry\" | |
+| RateLimited | | 429 | | Too many requests | |
+"
+    );
+}
+
+#[test]
+fn json() {
+    let json: serde_json::Value = serde_json::from_str(&catalog(&["--format", "json"])).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 6);
+    assert_eq!(
+        json[0],
+        serde_json::json!({
+            "type": "UserNotFound",
+            "variant": null,
+            "status": "404",
+            "error_code": "user_not_found",
+            "message": "User {0} not found",
+            "doc": "The user doesn't exist."
+        })
+    );
+    assert_eq!(json[3]["status"], "transparent");
+    assert_eq!(json[3]["message"], serde_json::Value::Null);
+}
+
+#[test]
+fn csv() {
+    let csv = catalog(&["--format=csv"]);
+    let lines: Vec<_> = csv.split("\r\n").collect();
+    assert_eq!(
+        lines[0],
+        "Type,Variant,Status,Error Code,Message,Description"
+    );
+    assert_eq!(
+        lines[2],
+        "ApiError,InvalidBody,400,invalid_body,Invalid body: {0},\"The request body is invalid, see the message for details.\""
+    );
+    assert_eq!(
+        lines[5],
+        "ApiError,Internal,500,,\"Internal error, \"\"sorry\"\"\","
+    );
+    assert_eq!(lines.len(), 8);
+}
+
+#[test]
+fn typescript() {
+    assert_eq!(
+        catalog(&["--format", "typescript"]),
+        "\
+// Generated by actix-web-error-catalog. Do not edit.
+
+/** Body of a JSON error response. */
+export interface ErrorBody<Code extends string = string> {
+  message: string;
+  code?: Code;
+}
+
+/** Error codes of `UserNotFound`. */
+export type UserNotFoundCode = \"user_not_found\";
+/** Body of `UserNotFound` responses. */
+export type UserNotFoundBody = ErrorBody<UserNotFoundCode>;
+
+/** Error codes of `ApiError`. */
+export type ApiErrorCode = \"invalid_body\";
+/** Body of `ApiError` responses. */
+export type ApiErrorBody = ErrorBody<ApiErrorCode>;
+"
+    );
+}
+
+#[test]
+fn typescript_dynamic_codes() {
+    let ts = catalog_of("dynamic.rs", &["--format", "ts"]);
+    assert!(ts.contains("export type DynamicErrorCode = string;\n"));
+    assert!(ts.contains("export type NoCodeCode = never;\n"));
+    assert!(!ts.contains("TextError"));
+}
+
+#[test]
+fn typescript_modules() {
+    let ts = catalog_of("modules", &["--format", "ts"]);
+    assert!(ts.contains(
+        "/** Error codes of `orders::Error`. */\nexport type OrdersErrorCode = \"order_not_found\";\n"
+    ));
+    assert!(ts.contains(
+        "/** Error codes of `users::Error`. */\nexport type UsersErrorCode = \"user_not_found\";\n"
+    ));
+    assert!(ts.contains(
+        "/** Error codes of `users::session::Expired`. */\nexport type ExpiredCode = \"session_expired\";\n"
+    ));
+}
+
+#[test]
+fn unknown_format() {
+    let output = Command::new(env!("CARGO_BIN_EXE_actix-web-error-catalog"))
+        .args(["--format", "yaml"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown format 'yaml'"));
+}
@@ -0,0 +1,111 @@
+use crate::{
+    attr::Attrs,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Generics, Ident};
+
+/// Decoding is opt-in with `#[decode]`, as it adds an inherent `from_response`.
+pub fn expand_struct(input: &Struct) -> TokenStream {
+    if input.attrs.decode.is_none() {
+        return TokenStream::new();
+    }
+    let checks = if input.fields.is_empty() {
+        vec![check(&input.attrs, &quote! { Self {} })]
+    } else {
+        Vec::new()
+    };
+    expand(&input.ident, input.generics, &checks)
+}
+
+/// See [`expand_struct`].
+pub fn expand_enum(input: &Enum) -> TokenStream {
+    if input.attrs.decode.is_none() {
+        return TokenStream::new();
+    }
+    let checks: Vec<_> = input
+        .variants
+        .iter()
+        .filter(|variant| variant.fields.is_empty())
+        .map(|variant| {
+            let ident = &variant.ident;
+            check(&variant.attrs, &quote! { Self::#ident {} })
+        })
+        .collect();
+    expand(&input.ident, input.generics, &checks)
+}
+
+/// Unit variants with an error code are matched by their code,
+/// unit variants without one by their message.
+fn check(attrs: &Attrs, value: &TokenStream) -> TokenStream {
+    match &attrs.error_code {
+        Some(error_code) => {
+            let code = error_code.error_code.static_tokens();
+            quote! {
+                if body.code.as_deref() == #code {
+                    return ::std::option::Option::Some(#value);
+                }
+            }
+        }
+        None => quote! {
+            if body.code.is_none() && body.message == ::std::string::ToString::to_string(&#value) {
+                return ::std::option::Option::Some(#value);
+            }
+        },
+    }
+}
+
+fn expand(ty: &Ident, generics: &Generics, checks: &[TokenStream]) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty #ty_generics: ::std::fmt::Display));
+    let decode_where_clause = where_clause.clone();
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty #ty_generics: ::actix_web::ResponseError));
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics #ty #ty_generics #decode_where_clause {
+            fn decode_error_body(body: &::actix_web_error::ErrorBody) -> ::std::option::Option<Self> {
+                #(#checks)*
+                ::std::option::Option::None
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// Decodes an error response with the given status.
+            ///
+            /// Only unit variants can be decoded. If the body doesn't match any, or
+            /// the status doesn't match the status of the variant, the body is returned.
+            pub fn from_response(
+                status: u16,
+                body: ::actix_web_error::ErrorBody,
+            ) -> ::std::result::Result<Self, ::actix_web_error::ErrorBody> {
+                match Self::decode_error_body(&body) {
+                    ::std::option::Option::Some(error)
+                        if ::actix_web::ResponseError::status_code(&error).as_u16() == status =>
+                    {
+                        ::std::result::Result::Ok(error)
+                    }
+                    _ => ::std::result::Result::Err(body),
+                }
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::std::convert::TryFrom<::actix_web_error::ErrorBody> for #ty #ty_generics #decode_where_clause {
+            type Error = ::actix_web_error::ErrorBody;
+
+            fn try_from(body: ::actix_web_error::ErrorBody) -> ::std::result::Result<Self, Self::Error> {
+                Self::decode_error_body(&body).ok_or(body)
+            }
+        }
+    }
+}
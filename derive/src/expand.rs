@@ -1,7 +1,7 @@
 use crate::{
-    attr::ResolveStatus,
+    attr::{Attrs, Code, ErrorCode, ResolveStatus, SourceStatus},
     generics::InferredBounds,
-    input::{Enum, Field, Input, Struct},
+    input::{binding, source_field, Enum, Field, Input, Struct},
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
@@ -9,8 +9,32 @@ use std::collections::BTreeSet;
 use syn::{DeriveInput, Member, Result};
 
 pub trait BodyExpander {
+    /// The statements of `error_response` for a struct, evaluating to the response.
     fn expand_struct(input: &Struct) -> TokenStream;
+    /// The statements of `error_response` for an enum, evaluating to the response.
+    ///
+    /// `#[response(transparent)]` variants are forwarded to their field before.
     fn expand_enum(input: &Enum) -> TokenStream;
+
+    /// The `ResponseFormat` of the body (used by `utoipa` responses, JSON schemas and translations)
+    /// for the container attributes `attrs`.
+    fn response_format(attrs: &Attrs) -> TokenStream;
+
+    /// Adds the bounds the body of a struct requires.
+    fn infer_struct_bounds(_: &Struct, _: &mut InferredBounds) {}
+
+    /// Adds the bounds the body of an enum requires.
+    fn infer_enum_bounds(_: &Enum, _: &mut InferredBounds) {}
+
+    /// Items generated next to the `ResponseError` implementation of a struct.
+    fn expand_struct_items(_: &Struct) -> TokenStream {
+        TokenStream::new()
+    }
+
+    /// Items generated next to the `ResponseError` implementation of an enum.
+    fn expand_enum_items(_: &Enum) -> TokenStream {
+        TokenStream::new()
+    }
 }
 
 pub fn expand<E: BodyExpander>(node: &DeriveInput) -> Result<TokenStream> {
@@ -25,6 +49,7 @@ fn impl_struct<E: BodyExpander>(input: &Struct) -> TokenStream {
     let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
 
     let mut implied_response_bounds = BTreeSet::new();
+    let mut inferred_response_bounds = InferredBounds::new();
     let status_body = match &input.attrs.status {
         Some(ResolveStatus::Transparent(_)) => {
             let only_field = &input.fields[0].member;
@@ -40,25 +65,94 @@ fn impl_struct<E: BodyExpander>(input: &Struct) -> TokenStream {
                 #status
             }
         }),
+        Some(ResolveStatus::Source(source)) => {
+            let field = source_field(&input.fields).unwrap();
+            if field.contains_generic {
+                inferred_response_bounds.insert(field.ty, source_bound());
+            }
+            let member = &field.member;
+            let status = source_status(&quote! { self.#member }, source);
+            Some(quote! {
+                fn status_code(&self) -> ::actix_web::http::StatusCode {
+                    #status
+                }
+            })
+        }
         None => None,
     };
-    let mut inferred_response_bounds = InferredBounds::new();
     for field in implied_response_bounds {
         let field = &input.fields[field];
         if field.contains_generic {
             inferred_response_bounds.insert(field.ty, quote! { ::actix_web::ResponseError });
         }
     }
+    infer_error_code_bounds(&input.attrs, &input.fields, &mut inferred_response_bounds);
+    crate::i18n::infer_struct_bounds(input, &mut inferred_response_bounds);
+    E::infer_struct_bounds(input, &mut inferred_response_bounds);
     let response_where_clause = inferred_response_bounds.augment_where_clause(input.generics);
-    let error_expansion = E::expand_struct(input);
+    let error_expansion = if input.attrs.response_transparent.is_some() {
+        let only_field = &input.fields[0].member;
+        quote! { ::actix_web::ResponseError::error_response(&self.#only_field) }
+    } else {
+        E::expand_struct(input)
+    };
+    let error_code = match &input.attrs.error_code {
+        Some(error_code) => error_code.error_code.tokens(),
+        None => quote! { None },
+    };
+    let items = E::expand_struct_items(input);
+    #[cfg(feature = "utoipa")]
+    let openapi = crate::openapi::expand_struct::<E>(input);
+    #[cfg(not(feature = "utoipa"))]
+    let openapi = TokenStream::new();
+    #[cfg(feature = "schemars")]
+    let schema = crate::schema::expand_struct::<E>(input);
+    #[cfg(not(feature = "schemars"))]
+    let schema = TokenStream::new();
+    #[cfg(feature = "tonic")]
+    let tonic = match input.attrs.grpc {
+        Some(_) => crate::tonic::expand_struct(input, &response_where_clause),
+        None => TokenStream::new(),
+    };
+    #[cfg(not(feature = "tonic"))]
+    let tonic = TokenStream::new();
+    #[cfg(feature = "async-graphql")]
+    let graphql = crate::graphql::expand_struct(input, &response_where_clause);
+    #[cfg(not(feature = "async-graphql"))]
+    let graphql = TokenStream::new();
+    #[cfg(feature = "ws")]
+    let ws = crate::ws::expand_struct(input, &response_where_clause);
+    #[cfg(not(feature = "ws"))]
+    let ws = TokenStream::new();
 
     quote! {
         #[allow(unused_qualifications)]
         impl #impl_generics ::actix_web::ResponseError for #ty #ty_generics #response_where_clause {
             #status_body
 
-            #error_expansion
+            fn error_response(&self) -> ::actix_web::HttpResponse<::actix_web::body::BoxBody> {
+                #error_expansion
+            }
         }
+
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::ErrorCode for #ty #ty_generics #response_where_clause {
+            fn error_code(&self) -> Option<&'static str> {
+                #error_code
+            }
+        }
+
+        #items
+
+        #openapi
+
+        #schema
+
+        #tonic
+
+        #graphql
+
+        #ws
     }
 }
 
@@ -82,6 +176,17 @@ fn impl_enum<E: BodyExpander>(input: &Enum) -> TokenStream {
                     quote! { ::actix_web::ResponseError::status_code(#only_field) }
                 }
                 ResolveStatus::Fixed(status) => status.code.to_token_stream(),
+                ResolveStatus::Source(source) => {
+                    let field = source_field(&variant.fields).unwrap();
+                    if field.contains_generic {
+                        inferred_bounds.insert(field.ty, source_bound());
+                    }
+                    let binding = match &field.member {
+                        Member::Named(ident) => ident.clone(),
+                        Member::Unnamed(idx) => format_ident!("_{}", idx),
+                    };
+                    source_status(&quote! { (*#binding) }, source)
+                }
             };
             let ident = &variant.ident;
             let pat = fields_pat(&variant.fields);
@@ -103,25 +208,137 @@ fn impl_enum<E: BodyExpander>(input: &Enum) -> TokenStream {
         })
     };
 
+    for variant in &input.variants {
+        infer_error_code_bounds(&variant.attrs, &variant.fields, &mut inferred_bounds);
+    }
+    crate::i18n::infer_enum_bounds(input, &mut inferred_bounds);
+    E::infer_enum_bounds(input, &mut inferred_bounds);
     let where_clause = inferred_bounds.augment_where_clause(input.generics);
-    let error_expansion = E::expand_enum(input);
+    let error_expansion = forward_responses(input, &E::expand_enum(input));
     let error_code_expansion = error_code(input);
+    let items = E::expand_enum_items(input);
+    #[cfg(feature = "utoipa")]
+    let openapi = crate::openapi::expand_enum::<E>(input);
+    #[cfg(not(feature = "utoipa"))]
+    let openapi = TokenStream::new();
+    #[cfg(feature = "schemars")]
+    let schema = crate::schema::expand_enum::<E>(input);
+    #[cfg(not(feature = "schemars"))]
+    let schema = TokenStream::new();
+    #[cfg(feature = "tonic")]
+    let tonic = match input.attrs.grpc {
+        Some(_) => crate::tonic::expand_enum(input, &where_clause),
+        None => TokenStream::new(),
+    };
+    #[cfg(not(feature = "tonic"))]
+    let tonic = TokenStream::new();
+    #[cfg(feature = "async-graphql")]
+    let graphql = crate::graphql::expand_enum(input, &where_clause);
+    #[cfg(not(feature = "async-graphql"))]
+    let graphql = TokenStream::new();
+    #[cfg(feature = "ws")]
+    let ws = crate::ws::expand_enum(input, &where_clause);
+    #[cfg(not(feature = "ws"))]
+    let ws = TokenStream::new();
 
     quote! {
         #[allow(unused_qualifications)]
         impl #impl_generics ::actix_web::ResponseError for #ty #ty_generics #where_clause {
             #status_body
 
-            #error_expansion
+            #[allow(unreachable_patterns, clippy::used_underscore_binding)]
+            fn error_response(&self) -> ::actix_web::HttpResponse<::actix_web::body::BoxBody> {
+                #error_expansion
+            }
         }
 
-        impl #ty #ty_generics #where_clause {
+        impl #impl_generics #ty #ty_generics #where_clause {
             #error_code_expansion
         }
+
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::ErrorCode for #ty #ty_generics #where_clause {
+            #error_code_expansion
+        }
+
+        #items
+
+        #openapi
+
+        #schema
+
+        #tonic
+
+        #graphql
+
+        #ws
+    }
+}
+
+/// Adds the bound of an `#[error_code(transparent)]` field with generics.
+pub fn infer_error_code_bounds(attrs: &Attrs, fields: &[Field], bounds: &mut InferredBounds) {
+    if let (
+        Some(ErrorCode {
+            error_code: Code::Transparent(_),
+            ..
+        }),
+        [field],
+    ) = (&attrs.error_code, fields)
+    {
+        if field.contains_generic {
+            bounds.insert(field.ty, quote! { ::actix_web_error::ErrorCode });
+        }
+    }
+}
+
+/// Forwards `error_response` of `#[response(transparent)]` variants to their field,
+/// `expansion` being the body generated by the body expander.
+fn forward_responses(input: &Enum, expansion: &TokenStream) -> TokenStream {
+    let ty = &input.ident;
+    let arms: Vec<_> = input
+        .variants
+        .iter()
+        .filter(|variant| variant.attrs.response_transparent.is_some())
+        .map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let only_field = binding(&variant.fields[0].member);
+            quote! {
+                #ty::#ident #pat => ::actix_web::ResponseError::error_response(#only_field)
+            }
+        })
+        .collect();
+    if arms.is_empty() {
+        return expansion.clone();
+    }
+
+    quote! {
+        match self {
+            #(#arms,)*
+            _ => { #expansion }
+        }
     }
 }
 
-fn fields_pat(fields: &[Field]) -> TokenStream {
+/// Expression evaluating to the status of the `#[status(source)]` field `place` (not a reference to it).
+///
+/// The field is converted with method syntax, so `Box<dyn Error>` and `anyhow::Error` deref to the error.
+fn source_status(place: &TokenStream, source: &SourceStatus) -> TokenStream {
+    let fallback = match &source.fallback {
+        Some(code) => code.to_token_stream(),
+        None => quote! { ::actix_web::http::StatusCode::INTERNAL_SERVER_ERROR },
+    };
+    quote! {{
+        use ::actix_web_error::__private::AsDynError as _;
+        ::actix_web_error::source::status_of(#place.as_dyn_error()).unwrap_or(#fallback)
+    }}
+}
+
+fn source_bound() -> TokenStream {
+    quote! { ::actix_web_error::__private::AsDynError }
+}
+
+pub fn fields_pat(fields: &[Field]) -> TokenStream {
     let mut members = fields.iter().map(|field| &field.member).peekable();
     match members.peek() {
         Some(Member::Named(_)) => quote!({ #(#members),* }),
@@ -137,6 +354,18 @@ fn fields_pat(fields: &[Field]) -> TokenStream {
 }
 
 fn error_code(input: &Enum) -> TokenStream {
+    let error_code = error_code_expr(input, &quote! { self });
+
+    quote! {
+        #[allow(unused_variables, clippy::used_underscore_binding)]
+        fn error_code(&self) -> Option<&'static str> {
+            #error_code
+        }
+    }
+}
+
+/// Expression evaluating to the error code of `scrutinee` (`&Self`).
+pub fn error_code_expr(input: &Enum, scrutinee: &TokenStream) -> TokenStream {
     let ty = &input.ident;
 
     let arms = input.variants.iter().map(|v| {
@@ -151,10 +380,8 @@ fn error_code(input: &Enum) -> TokenStream {
     let arms: Vec<_> = arms.collect();
 
     quote! {
-        fn error_code(&self) -> Option<&'static str> {
-            match self {
-                #(#arms,)*
-            }
+        match #scrutinee {
+            #(#arms,)*
         }
     }
 }
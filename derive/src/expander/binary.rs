@@ -0,0 +1,78 @@
+//! Binary formats serializing the same structure as `Json`.
+
+use super::render_response;
+use crate::{
+    attr::Attrs,
+    expand::BodyExpander,
+    i18n,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[cfg(feature = "msgpack")]
+pub struct MsgPack;
+
+#[cfg(feature = "msgpack")]
+impl BodyExpander for MsgPack {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        expand_struct(
+            input,
+            &Self::response_format(&input.attrs),
+            "application/msgpack",
+        )
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        expand_enum(
+            input,
+            &Self::response_format(&input.attrs),
+            "application/msgpack",
+        )
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::MsgPack }
+    }
+}
+
+#[cfg(feature = "cbor")]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl BodyExpander for Cbor {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        expand_struct(
+            input,
+            &Self::response_format(&input.attrs),
+            "application/cbor",
+        )
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        expand_enum(
+            input,
+            &Self::response_format(&input.attrs),
+            "application/cbor",
+        )
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::Cbor }
+    }
+}
+
+fn expand_struct(input: &Struct, format: &TokenStream, content_type: &str) -> TokenStream {
+    let code = match &input.attrs.error_code {
+        Some(error_code) => error_code.error_code.tokens(),
+        None => quote! { None },
+    };
+    let localize = i18n::expand_struct(input, format, &code);
+    render_response(format, content_type, &code, localize.as_ref())
+}
+
+fn expand_enum(input: &Enum, format: &TokenStream, content_type: &str) -> TokenStream {
+    let code = quote! { self.error_code() };
+    let localize = i18n::expand_enum(input, format, &code);
+    render_response(format, content_type, &code, localize.as_ref())
+}
@@ -0,0 +1,51 @@
+use super::render_response;
+use crate::{
+    attr::Attrs,
+    expand::BodyExpander,
+    grpc, i18n,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub struct GoogleJson;
+
+impl BodyExpander for GoogleJson {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let code = match &input.attrs.error_code {
+            Some(error_code) => error_code.error_code.tokens(),
+            None => quote! { None },
+        };
+        let format = format(&grpc::struct_code(&input.attrs, &quote! { status }));
+        let localize = i18n::expand_struct(input, &format, &code);
+        render_response(&format, "application/json", &code, localize.as_ref())
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let code = quote! { self.error_code() };
+        let format = format(&grpc::enum_code(
+            input,
+            &quote! { self },
+            &quote! { status },
+        ));
+        let localize = i18n::expand_enum(input, &format, &code);
+        render_response(&format, "application/json", &code, localize.as_ref())
+    }
+
+    fn response_format(attrs: &Attrs) -> TokenStream {
+        let grpc_code = match &attrs.grpc_code {
+            Some(code) => quote! {
+                ::std::option::Option::Some(::actix_web_error::grpc::GrpcCode::#code)
+            },
+            None => quote! { ::std::option::Option::None },
+        };
+        quote! { ::actix_web_error::__private::ResponseFormat::Google(#grpc_code) }
+    }
+}
+
+/// The format of a response with the gRPC code `grpc_code`.
+fn format(grpc_code: &TokenStream) -> TokenStream {
+    quote! {
+        ::actix_web_error::__private::ResponseFormat::Google(::std::option::Option::Some(#grpc_code))
+    }
+}
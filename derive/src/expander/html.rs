@@ -0,0 +1,69 @@
+use super::render_response;
+use crate::{
+    attr::Attrs,
+    expand::BodyExpander,
+    i18n,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub struct Html;
+
+impl BodyExpander for Html {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let code = match &input.attrs.error_code {
+            Some(error_code) => error_code.error_code.tokens(),
+            None => quote! { None },
+        };
+        let format = Self::response_format(&input.attrs);
+        let localize = i18n::expand_struct(input, &format, &code);
+        render_response(
+            &format,
+            "text/html; charset=utf-8",
+            &code,
+            localize.as_ref(),
+        )
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let code = quote! { self.error_code() };
+        let format = if input
+            .variants
+            .iter()
+            .any(|variant| variant.attrs.html_template.is_some())
+        {
+            // variants without a template use the one of the enum
+            let ty = &input.ident;
+            let arms = input.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let format = match &variant.attrs.html_template {
+                    Some(_) => Self::response_format(&variant.attrs),
+                    None => Self::response_format(&input.attrs),
+                };
+                quote! { #ty::#ident { .. } => #format }
+            });
+            quote! { (match self { #(#arms,)* }) }
+        } else {
+            Self::response_format(&input.attrs)
+        };
+        let localize = i18n::expand_enum(input, &format, &code);
+        render_response(
+            &format,
+            "text/html; charset=utf-8",
+            &code,
+            localize.as_ref(),
+        )
+    }
+
+    fn response_format(attrs: &Attrs) -> TokenStream {
+        match &attrs.html_template {
+            Some(template) => {
+                quote! { ::actix_web_error::__private::ResponseFormat::Html(#template) }
+            }
+            None => quote! {
+                ::actix_web_error::__private::ResponseFormat::Html(::actix_web_error::html::default_template)
+            },
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
+    attr::Attrs,
+    decode,
     expand::BodyExpander,
+    i18n,
     input::{Enum, Struct},
 };
 use proc_macro2::TokenStream;
@@ -8,27 +11,68 @@ use quote::quote;
 pub struct Json;
 
 impl BodyExpander for Json {
-    fn expand_struct(_: &Struct) -> TokenStream {
-        json_expand()
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let code = match &input.attrs.error_code {
+            Some(error_code) => error_code.error_code.tokens(),
+            None => quote! { None },
+        };
+        let localize = i18n::expand_struct(input, &Self::response_format(&input.attrs), &code);
+        json_expand(&code, localize)
     }
 
-    fn expand_enum(_: &Enum) -> TokenStream {
-        json_expand_enum()
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let code = quote! { self.error_code() };
+        let localize = i18n::expand_enum(input, &Self::response_format(&input.attrs), &code);
+        json_expand(&code, localize)
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::Json }
+    }
+
+    fn expand_struct_items(input: &Struct) -> TokenStream {
+        decode::expand_struct(input)
+    }
+
+    fn expand_enum_items(input: &Enum) -> TokenStream {
+        decode::expand_enum(input)
+    }
+}
+
+fn json_expand(code: &TokenStream, localize: Option<TokenStream>) -> TokenStream {
+    let response = quote! {
+        ::actix_web::HttpResponseBuilder::new(self.status_code()).json(::actix_web_error::__private::JsonErrorSerialize { message: &self, code: #code })
+    };
+    match localize {
+        Some(localize) => quote! {
+            let mut res = #response;
+            #localize
+            res
+        },
+        None => response,
     }
 }
 
-fn json_expand_enum() -> TokenStream {
-    quote! {
-        fn error_response(&self) -> ::actix_web::HttpResponse<::actix_web::body::BoxBody> {
-            ::actix_web::HttpResponseBuilder::new(self.status_code()).json(::actix_web_error::__private::JsonErrorSerialize { message: &self, code: self.error_code() } )
+#[cfg(feature = "axum")]
+impl crate::target::BodyExpander<crate::target::Axum> for Json {
+    fn expand_body() -> TokenStream {
+        quote! {
+            ::axum::Json(::actix_web_error::__private::JsonErrorSerialize { message: &message, code })
         }
     }
 }
 
-fn json_expand() -> TokenStream {
-    quote! {
-        fn error_response(&self) -> ::actix_web::HttpResponse<::actix_web::body::BoxBody> {
-            ::actix_web::HttpResponseBuilder::new(self.status_code()).json(::actix_web_error::__private::JsonErrorSerialize { message: &self })
+#[cfg(feature = "http")]
+impl crate::target::BodyExpander<crate::target::Http> for Json {
+    fn expand_body() -> TokenStream {
+        quote! {
+            (
+                "application/json",
+                ::actix_web_error::__private::serde_json::to_vec(
+                    &::actix_web_error::__private::JsonErrorSerialize { message: &message, code },
+                )
+                .expect("serializing strings can't fail"),
+            )
         }
     }
 }
@@ -0,0 +1,148 @@
+use crate::{
+    attr::{Attrs, JsonApiSource, SourceKind},
+    expand::{fields_pat, BodyExpander},
+    generics::InferredBounds,
+    input::{Enum, Field, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Member};
+
+pub struct JsonApi;
+
+impl BodyExpander for JsonApi {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let code = match &input.attrs.error_code {
+            Some(error_code) => error_code.error_code.tokens(),
+            None => quote! { None },
+        };
+        let source = source(
+            &input.attrs,
+            &input.fields,
+            |member| quote! { self.#member },
+        )
+        .unwrap_or_else(Error::into_compile_error);
+        json_api_expand(&code, &source)
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let ty = &input.ident;
+        let arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let source = source(&variant.attrs, &variant.fields, |member| match member {
+                Member::Named(ident) => quote! { #ident },
+                Member::Unnamed(index) => {
+                    let var = format_ident!("_{}", index);
+                    quote! { #var }
+                }
+            })
+            .unwrap_or_else(Error::into_compile_error);
+            quote! { #ty::#ident #pat => #source }
+        });
+        let source = quote! {
+            match self {
+                #(#arms,)*
+            }
+        };
+        json_api_expand(&quote! { self.error_code() }, &source)
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::JsonApi }
+    }
+
+    fn infer_struct_bounds(input: &Struct, bounds: &mut InferredBounds) {
+        infer_bounds(&input.fields, bounds);
+    }
+
+    fn infer_enum_bounds(input: &Enum, bounds: &mut InferredBounds) {
+        for variant in &input.variants {
+            infer_bounds(&variant.fields, bounds);
+        }
+    }
+}
+
+/// Expression evaluating to the `Option<JsonApiSource>` of a struct or variant.
+fn source(
+    attrs: &Attrs,
+    fields: &[Field],
+    access: impl Fn(&Member) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    let field = fields.iter().find_map(|field| {
+        field
+            .attrs
+            .json_api_source
+            .as_ref()
+            .map(|source| (field, source))
+    });
+    let (kind, value) = match (&attrs.json_api_source, field) {
+        (Some(_), Some((_, field_source))) => {
+            return Err(Error::new_spanned(
+                field_source.original,
+                "duplicate source, #[json_api(..)] is already set on the struct or variant",
+            ));
+        }
+        (Some(source), None) => match &source.value {
+            Some(value) => (source.kind, quote! { #value }),
+            None => {
+                return Err(Error::new_spanned(
+                    source.original,
+                    "expected a value, e.g. #[json_api(pointer = \"/data\")]",
+                ))
+            }
+        },
+        (None, Some((field, source))) => match &source.value {
+            Some(value) => (source.kind, quote! { #value }),
+            None => {
+                let value = access(&field.member);
+                (
+                    source.kind,
+                    quote! { ::std::string::ToString::to_string(&#value) },
+                )
+            }
+        },
+        (None, None) => return Ok(quote! { ::std::option::Option::None }),
+    };
+
+    let constructor = match kind {
+        SourceKind::Pointer => quote! { pointer },
+        SourceKind::Parameter => quote! { parameter },
+    };
+    Ok(quote! {
+        ::std::option::Option::Some(::actix_web_error::json_api::JsonApiSource::#constructor(#value))
+    })
+}
+
+/// Fields used as the source need to implement `Display`.
+fn infer_bounds(fields: &[Field], bounds: &mut InferredBounds) {
+    for field in fields {
+        if let Some(JsonApiSource { value: None, .. }) = field.attrs.json_api_source {
+            if field.contains_generic {
+                bounds.insert(field.ty, quote! { ::std::fmt::Display });
+            }
+        }
+    }
+}
+
+fn json_api_expand(code: &TokenStream, source: &TokenStream) -> TokenStream {
+    quote! {
+        let status = self.status_code();
+        #[allow(unused_variables, clippy::used_underscore_binding)]
+        let source: ::std::option::Option<::actix_web_error::json_api::JsonApiSource> = #source;
+        let document = ::actix_web_error::json_api::JsonApiDocument::from(
+            ::actix_web_error::json_api::JsonApiError::new(
+                status,
+                ::std::string::ToString::to_string(self),
+                #code,
+                source,
+            ),
+        );
+        ::actix_web::HttpResponseBuilder::new(status)
+            .content_type(::actix_web_error::json_api::CONTENT_TYPE)
+            .body(
+                ::actix_web_error::__private::serde_json::to_vec(&document)
+                    .expect("serializing strings can't fail"),
+            )
+    }
+}
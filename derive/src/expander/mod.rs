@@ -1,5 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+mod binary;
+mod google;
+mod html;
 mod json;
+mod json_api;
 mod text;
+mod xml;
 
+#[cfg(feature = "cbor")]
+pub use binary::Cbor;
+#[cfg(feature = "msgpack")]
+pub use binary::MsgPack;
+pub use google::GoogleJson;
+pub use html::Html;
 pub use json::Json;
+pub use json_api::JsonApi;
 pub use text::Text;
+pub use xml::Xml;
+
+/// A response with a body rendered by the runtime `ResponseFormat` `format`.
+fn render_response(
+    format: &TokenStream,
+    content_type: &str,
+    code: &TokenStream,
+    localize: Option<&TokenStream>,
+) -> TokenStream {
+    quote! {
+        let status = self.status_code();
+        let body = #format.render(status, &::std::string::ToString::to_string(self), #code);
+        #[allow(unused_mut)]
+        let mut res = ::actix_web::HttpResponseBuilder::new(status)
+            .content_type(#content_type)
+            .body(body);
+        #localize
+        res
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
+    attr::Attrs,
     expand::BodyExpander,
+    i18n,
     input::{Enum, Struct},
 };
 use proc_macro2::TokenStream;
@@ -8,15 +10,51 @@ use quote::quote;
 pub struct Text;
 
 impl BodyExpander for Text {
-    fn expand_struct(_: &Struct) -> TokenStream {
-        expand_text()
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let localize = i18n::expand_struct(
+            input,
+            &Self::response_format(&input.attrs),
+            &quote! { None },
+        );
+        expand_text(localize.as_ref())
     }
 
-    fn expand_enum(_: &Enum) -> TokenStream {
-        expand_text()
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let localize = i18n::expand_enum(
+            input,
+            &Self::response_format(&input.attrs),
+            &quote! { None },
+        );
+        expand_text(localize.as_ref())
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::Text }
+    }
+}
+
+/// The response of the default `error_response`, with the message localized.
+fn expand_text(localize: Option<&TokenStream>) -> TokenStream {
+    quote! {
+        #[allow(unused_mut)]
+        let mut res = ::actix_web::HttpResponseBuilder::new(self.status_code())
+            .content_type(::actix_web::http::header::ContentType::plaintext())
+            .body(::std::string::ToString::to_string(self));
+        #localize
+        res
     }
 }
 
-fn expand_text() -> TokenStream {
-    quote!()
+#[cfg(feature = "axum")]
+impl crate::target::BodyExpander<crate::target::Axum> for Text {
+    fn expand_body() -> TokenStream {
+        quote! { message }
+    }
+}
+
+#[cfg(feature = "http")]
+impl crate::target::BodyExpander<crate::target::Http> for Text {
+    fn expand_body() -> TokenStream {
+        quote! { ("text/plain; charset=utf-8", message.into_bytes()) }
+    }
 }
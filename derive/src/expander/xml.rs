@@ -0,0 +1,34 @@
+use super::render_response;
+use crate::{
+    attr::Attrs,
+    expand::BodyExpander,
+    i18n,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub struct Xml;
+
+impl BodyExpander for Xml {
+    fn expand_struct(input: &Struct) -> TokenStream {
+        let code = match &input.attrs.error_code {
+            Some(error_code) => error_code.error_code.tokens(),
+            None => quote! { None },
+        };
+        let format = Self::response_format(&input.attrs);
+        let localize = i18n::expand_struct(input, &format, &code);
+        render_response(&format, "application/xml", &code, localize.as_ref())
+    }
+
+    fn expand_enum(input: &Enum) -> TokenStream {
+        let code = quote! { self.error_code() };
+        let format = Self::response_format(&input.attrs);
+        let localize = i18n::expand_enum(input, &format, &code);
+        render_response(&format, "application/xml", &code, localize.as_ref())
+    }
+
+    fn response_format(_: &Attrs) -> TokenStream {
+        quote! { ::actix_web_error::__private::ResponseFormat::Xml }
+    }
+}
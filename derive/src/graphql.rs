@@ -0,0 +1,121 @@
+use crate::input::{Enum, Field, Struct};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Generics, Ident, Member, WhereClause};
+
+/// `async_graphql::ErrorExtensions`, `where_clause` being the one of the `ResponseError` implementation.
+pub fn expand_struct(input: &Struct, where_clause: &WhereClause) -> TokenStream {
+    let code = match &input.attrs.error_code {
+        Some(error_code) => error_code.error_code.tokens(),
+        None => quote! { ::std::option::Option::None },
+    };
+    let details = input
+        .fields
+        .iter()
+        .filter(|field| field.attrs.detail.is_some())
+        .map(|field| {
+            let member = &field.member;
+            set_detail(&field.member, &quote! { &self.#member })
+        });
+    let details = quote! { #(#details)* };
+    let where_clause = augment_where_clause(where_clause, input.fields.iter());
+    expand(&input.ident, input.generics, &where_clause, &code, &details)
+}
+
+/// See [`expand_struct`].
+pub fn expand_enum(input: &Enum, where_clause: &WhereClause) -> TokenStream {
+    let ty = &input.ident;
+    let arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let fields: Vec<_> = variant
+            .fields
+            .iter()
+            .filter(|field| field.attrs.detail.is_some())
+            .collect();
+        let bindings = fields.iter().map(|field| {
+            let var = binding(&field.member);
+            let member = &field.member;
+            quote! { #member: #var }
+        });
+        let details = fields.iter().map(|field| {
+            let var = binding(&field.member);
+            set_detail(&field.member, &quote! { #var })
+        });
+        quote! { #ty::#ident { #(#bindings,)* .. } => { #(#details)* } }
+    });
+    let details = quote! {
+        match self {
+            #(#arms)*
+        }
+    };
+    let fields = input.variants.iter().flat_map(|variant| &variant.fields);
+    let where_clause = augment_where_clause(where_clause, fields);
+    expand(
+        &input.ident,
+        input.generics,
+        &where_clause,
+        &quote! { self.error_code() },
+        &details,
+    )
+}
+
+/// The variable a `#[detail]` field of a variant is bound to.
+fn binding(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => format_ident!("detail_{}", ident),
+        Member::Unnamed(index) => format_ident!("detail_{}", index),
+    }
+}
+
+fn set_detail(member: &Member, value: &TokenStream) -> TokenStream {
+    let name = match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    };
+    quote! {
+        ::actix_web_error::__private::set_graphql_detail(&mut error, #name, #value);
+    }
+}
+
+/// `#[detail]` fields need to implement `Serialize`.
+fn augment_where_clause<'a>(
+    where_clause: &WhereClause,
+    fields: impl Iterator<Item = &'a Field<'a>>,
+) -> WhereClause {
+    let mut where_clause = where_clause.clone();
+    for field in fields {
+        if field.attrs.detail.is_some() && field.contains_generic {
+            let ty = field.ty;
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::actix_web_error::__private::serde::Serialize));
+        }
+    }
+    where_clause
+}
+
+fn expand(
+    ty: &Ident,
+    generics: &Generics,
+    where_clause: &WhereClause,
+    code: &TokenStream,
+    details: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::__private::async_graphql::ErrorExtensions for #ty #ty_generics #where_clause {
+            fn extend(&self) -> ::actix_web_error::__private::async_graphql::Error {
+                #[allow(unused_mut)]
+                let mut error = ::actix_web_error::__private::graphql_error(
+                    ::std::string::ToString::to_string(self),
+                    ::actix_web::ResponseError::status_code(self),
+                    #code,
+                );
+                #details
+                error
+            }
+        }
+    }
+}
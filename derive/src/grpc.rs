@@ -0,0 +1,42 @@
+//! gRPC codes of errors (`#[grpc(code = ..)]`).
+
+use crate::{attr::Attrs, input::Enum};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Expression evaluating to the `GrpcCode` of a struct with the container attributes `attrs`.
+///
+/// Without a `#[grpc(code = ..)]`, the code is derived from the `status` expression.
+pub fn struct_code(attrs: &Attrs, status: &TokenStream) -> TokenStream {
+    code(attrs, None, status)
+}
+
+/// Expression evaluating to the `GrpcCode` of `scrutinee` (`&Self`, an enum).
+///
+/// Variants without a `#[grpc(code = ..)]` use the one of the enum,
+/// then the one derived from the `status` expression.
+pub fn enum_code(input: &Enum, scrutinee: &TokenStream, status: &TokenStream) -> TokenStream {
+    let ty = &input.ident;
+    let arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let code = code(&variant.attrs, Some(&input.attrs), status);
+        quote! { #ty::#ident { .. } => #code }
+    });
+
+    quote! {
+        match #scrutinee {
+            #(#arms,)*
+        }
+    }
+}
+
+fn code(attrs: &Attrs, parent: Option<&Attrs>, status: &TokenStream) -> TokenStream {
+    let declared = attrs
+        .grpc_code
+        .as_ref()
+        .or_else(|| parent.and_then(|parent| parent.grpc_code.as_ref()));
+    match declared {
+        Some(code) => quote! { ::actix_web_error::grpc::GrpcCode::#code },
+        None => quote! { ::actix_web_error::grpc::GrpcCode::from_http_status(#status) },
+    }
+}
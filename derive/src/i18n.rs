@@ -0,0 +1,114 @@
+//! Translation keys attached to error responses (`#[message(key = "..")]`).
+
+use crate::{
+    expand::fields_pat,
+    generics::InferredBounds,
+    input::{Enum, Field, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Member;
+
+/// Statements attaching the translation key of `self` to the response `res`,
+/// `None` if there's no `#[message(key = "..")]`.
+///
+/// `code` is an expression evaluating to the error code in the body.
+pub fn expand_struct(
+    input: &Struct,
+    format: &TokenStream,
+    code: &TokenStream,
+) -> Option<TokenStream> {
+    let key = input.attrs.message_key.as_ref()?;
+    let args = args(&input.fields, |member| quote! { self.#member });
+    Some(attach(&quote! { (#key, #args) }, format, code))
+}
+
+/// See [`expand_struct`].
+pub fn expand_enum(input: &Enum, format: &TokenStream, code: &TokenStream) -> Option<TokenStream> {
+    if input
+        .variants
+        .iter()
+        .all(|variant| variant.attrs.message_key.is_none())
+    {
+        return None;
+    }
+
+    let ty = &input.ident;
+    let arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let pat = fields_pat(&variant.fields);
+        let message = match &variant.attrs.message_key {
+            Some(key) => {
+                let args = args(&variant.fields, |member| match member {
+                    Member::Named(ident) => quote! { #ident },
+                    Member::Unnamed(index) => {
+                        let var = format_ident!("_{}", index);
+                        quote! { #var }
+                    }
+                });
+                quote! { ::std::option::Option::Some((#key, #args)) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        quote! { #ty::#ident #pat => #message }
+    });
+    let attach = attach(&quote! { message }, format, code);
+
+    Some(quote! {
+        #[allow(unused_variables, clippy::used_underscore_binding)]
+        let message: ::std::option::Option<(&'static str, ::std::vec::Vec<(&'static str, ::std::string::String)>)> = match self {
+            #(#arms,)*
+        };
+        if let ::std::option::Option::Some(message) = message {
+            #attach
+        }
+    })
+}
+
+/// Fields used in translations need to implement `Display`.
+pub fn infer_struct_bounds(input: &Struct, bounds: &mut InferredBounds) {
+    if input.attrs.message_key.is_some() {
+        infer_bounds(&input.fields, bounds);
+    }
+}
+
+/// See [`infer_struct_bounds`].
+pub fn infer_enum_bounds(input: &Enum, bounds: &mut InferredBounds) {
+    for variant in &input.variants {
+        if variant.attrs.message_key.is_some() {
+            infer_bounds(&variant.fields, bounds);
+        }
+    }
+}
+
+fn infer_bounds(fields: &[Field], bounds: &mut InferredBounds) {
+    for field in fields.iter().filter(|field| field.contains_generic) {
+        bounds.insert(field.ty, quote! { ::std::fmt::Display });
+    }
+}
+
+/// The arguments of a translation, named after the fields (`0`, `1`, .. for tuples).
+fn args(fields: &[Field], access: impl Fn(&Member) -> TokenStream) -> TokenStream {
+    let args = fields.iter().map(|field| {
+        let name = match &field.member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let value = access(&field.member);
+        quote! { (#name, ::std::string::ToString::to_string(&#value)) }
+    });
+    quote! { ::std::vec![#(#args),*] }
+}
+
+fn attach(message: &TokenStream, format: &TokenStream, code: &TokenStream) -> TokenStream {
+    quote! {
+        let (key, args) = #message;
+        ::actix_web_error::__private::LocalizedMessage {
+            key,
+            args,
+            code: #code,
+            format: #format,
+        }
+        .attach(&mut res);
+    }
+}
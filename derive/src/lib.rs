@@ -1,17 +1,39 @@
+#![allow(
+    clippy::single_match_else,
+    clippy::struct_field_names,
+    clippy::too_many_lines
+)]
+
 extern crate proc_macro;
 
-mod attr;
+mod decode;
 mod expand;
 mod expander;
-mod generics;
-mod input;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+mod grpc;
+mod i18n;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(any(feature = "axum", feature = "http"))]
+mod target;
+#[cfg(feature = "tonic")]
+mod tonic;
+#[cfg(feature = "ws")]
+mod ws;
 
+use actix_web_error_internals::{attr, generics, input};
 use expand::expand;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(Json, attributes(status, error_code))]
+#[proc_macro_derive(
+    Json,
+    attributes(status, error_code, message, grpc, detail, ws_close, response, decode)
+)]
 pub fn derive_json(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     expand::<expander::Json>(&input)
@@ -19,10 +41,114 @@ pub fn derive_json(input: TokenStream) -> TokenStream {
         .into()
 }
 
-#[proc_macro_derive(Text, attributes(status))]
+#[proc_macro_derive(Text, attributes(status, message, grpc, detail, ws_close, response))]
 pub fn derive_text(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     expand::<expander::Text>(&input)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+#[proc_macro_derive(
+    Html,
+    attributes(status, error_code, message, html, grpc, detail, ws_close, response)
+)]
+pub fn derive_html(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::Html>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(
+    Xml,
+    attributes(status, error_code, message, grpc, detail, ws_close, response)
+)]
+pub fn derive_xml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::Xml>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(
+    JsonApi,
+    attributes(status, error_code, json_api, grpc, detail, ws_close, response)
+)]
+pub fn derive_json_api(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::JsonApi>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(
+    GoogleJson,
+    attributes(status, error_code, message, grpc, detail, ws_close, response)
+)]
+pub fn derive_google_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::GoogleJson>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "msgpack")]
+#[proc_macro_derive(
+    MsgPack,
+    attributes(status, error_code, message, grpc, detail, ws_close, response)
+)]
+pub fn derive_msgpack(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::MsgPack>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "cbor")]
+#[proc_macro_derive(
+    Cbor,
+    attributes(status, error_code, message, grpc, detail, ws_close, response)
+)]
+pub fn derive_cbor(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::<expander::Cbor>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "axum")]
+#[proc_macro_derive(AxumJson, attributes(status, error_code, message))]
+pub fn derive_axum_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    target::expand::<target::Axum, expander::Json>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "axum")]
+#[proc_macro_derive(AxumText, attributes(status, message))]
+pub fn derive_axum_text(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    target::expand::<target::Axum, expander::Text>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "http")]
+#[proc_macro_derive(HttpJson, attributes(status, error_code, message))]
+pub fn derive_http_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    target::expand::<target::Http, expander::Json>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "http")]
+#[proc_macro_derive(HttpText, attributes(status, message))]
+pub fn derive_http_text(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    target::expand::<target::Http, expander::Text>(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,169 @@
+use crate::{
+    attr::{Attrs, Code, ResolveStatus},
+    expand::BodyExpander,
+    generics::InferredBounds,
+    input::{forwarded_field, Enum, Field, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident};
+
+pub fn expand_struct<E: BodyExpander>(input: &Struct) -> TokenStream {
+    match forwarded_field(&input.attrs, &input.fields) {
+        Some(field) => expand::<E>(&input.ident, input.generics, &input.attrs, &[], &[field]),
+        None => {
+            let entry = entry(&input.ident.to_string(), &input.attrs);
+            expand::<E>(&input.ident, input.generics, &input.attrs, &[entry], &[])
+        }
+    }
+}
+
+pub fn expand_enum<E: BodyExpander>(input: &Enum) -> TokenStream {
+    let mut entries = Vec::new();
+    let mut forwarded = Vec::new();
+    for variant in &input.variants {
+        match forwarded_field(&variant.attrs, &variant.fields) {
+            Some(field) => forwarded.push(field),
+            None => entries.push(entry(&variant.ident.to_string(), &variant.attrs)),
+        }
+    }
+    expand::<E>(
+        &input.ident,
+        input.generics,
+        &input.attrs,
+        &entries,
+        &forwarded,
+    )
+}
+
+fn entry(name: &str, attrs: &Attrs) -> TokenStream {
+    let status = match &attrs.status {
+        Some(ResolveStatus::Fixed(status)) => {
+            quote! { ::std::option::Option::Some(#status.as_u16()) }
+        }
+        Some(ResolveStatus::Transparent(_) | ResolveStatus::Source(_)) => {
+            quote! { ::std::option::Option::None }
+        }
+        // `ResponseError`'s default
+        None => quote! { ::std::option::Option::Some(500) },
+    };
+    let message = match attrs
+        .display
+        .as_ref()
+        .and_then(|display| example_message(&display.value()))
+    {
+        Some(message) => quote! { ::std::option::Option::Some(#message) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let code = match &attrs.error_code {
+        // the code of the wrapped error isn't known, shown like placeholders of messages
+        Some(error_code) if matches!(error_code.error_code, Code::Transparent(_)) => {
+            quote! { ::std::option::Option::Some("<code>") }
+        }
+        Some(error_code) => error_code.error_code.static_tokens(),
+        None => quote! { ::std::option::Option::None },
+    };
+    let grpc_code = match &attrs.grpc_code {
+        Some(grpc_code) => quote! {
+            ::std::option::Option::Some(::actix_web_error::grpc::GrpcCode::#grpc_code)
+        },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    quote! {
+        ::actix_web_error::__private::ResponseEntry {
+            status: #status,
+            name: #name,
+            message: #message,
+            code: #code,
+            grpc_code: #grpc_code,
+        }
+    }
+}
+
+/// The `#[error("..")]` format string with placeholders replaced by their argument (`{id:?}` -> `<id>`).
+///
+/// Positional placeholders (`{}`) can't be named, so there's no example for them.
+fn example_message(format: &str) -> Option<String> {
+    let mut message = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                message.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                message.push('}');
+            }
+            '{' => {
+                let placeholder: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let argument = placeholder.split(':').next().unwrap_or_default().trim();
+                if argument.is_empty() {
+                    return None;
+                }
+                message.push('<');
+                message.push_str(argument);
+                message.push('>');
+            }
+            c => message.push(c),
+        }
+    }
+    Some(message)
+}
+
+/// `forwarded` being the fields of `#[response(transparent)]` structs or variants.
+fn expand<E: BodyExpander>(
+    ty: &Ident,
+    generics: &Generics,
+    attrs: &Attrs,
+    entries: &[TokenStream],
+    forwarded: &[&Field],
+) -> TokenStream {
+    let mut inferred_bounds = InferredBounds::new();
+    for field in forwarded {
+        if field.contains_generic {
+            inferred_bounds.insert(
+                field.ty,
+                quote! { ::actix_web_error::__private::utoipa::IntoResponses },
+            );
+        }
+    }
+    let where_clause = inferred_bounds.augment_where_clause(generics);
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let format = E::response_format(attrs);
+    let responses = quote! {
+        ::actix_web_error::__private::utoipa_responses(
+            #format,
+            &[#(#entries),*],
+        )
+    };
+    let responses = if forwarded.is_empty() {
+        responses
+    } else {
+        let forwarded = forwarded.iter().map(|field| field.ty);
+        quote! {
+            let mut responses = #responses;
+            #(
+                ::actix_web_error::__private::utoipa_forward_responses(
+                    &mut responses,
+                    <#forwarded as ::actix_web_error::__private::utoipa::IntoResponses>::responses(),
+                );
+            )*
+            responses
+        }
+    };
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::__private::utoipa::IntoResponses for #ty #ty_generics #where_clause {
+            fn responses() -> ::std::collections::BTreeMap<
+                ::std::string::String,
+                ::actix_web_error::__private::utoipa::openapi::RefOr<::actix_web_error::__private::utoipa::openapi::response::Response>,
+            > {
+                #responses
+            }
+        }
+    }
+}
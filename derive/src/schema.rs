@@ -0,0 +1,120 @@
+use crate::{
+    attr::{Attrs, Code, ErrorCode},
+    expand::BodyExpander,
+    generics::InferredBounds,
+    input::{forwarded_field, Enum, Field, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident};
+
+pub fn expand_struct<E: BodyExpander>(input: &Struct) -> TokenStream {
+    match forwarded_field(&input.attrs, &input.fields) {
+        Some(field) => expand::<E>(&input.ident, input.generics, &input.attrs, &[], &[field]),
+        None => expand::<E>(
+            &input.ident,
+            input.generics,
+            &input.attrs,
+            &[&input.attrs],
+            &[],
+        ),
+    }
+}
+
+pub fn expand_enum<E: BodyExpander>(input: &Enum) -> TokenStream {
+    let mut own = Vec::new();
+    let mut forwarded = Vec::new();
+    for variant in &input.variants {
+        match forwarded_field(&variant.attrs, &variant.fields) {
+            Some(field) => forwarded.push(field),
+            None => own.push(&variant.attrs),
+        }
+    }
+    expand::<E>(&input.ident, input.generics, &input.attrs, &own, &forwarded)
+}
+
+fn code(attrs: &Attrs) -> TokenStream {
+    match &attrs.error_code {
+        Some(error_code) => error_code.error_code.static_tokens(),
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+fn is_transparent_code(attrs: &Attrs) -> bool {
+    matches!(
+        attrs.error_code,
+        Some(ErrorCode {
+            error_code: Code::Transparent(_),
+            ..
+        })
+    )
+}
+
+/// `own` being the attributes of the struct or variants responding in the format of `attrs`,
+/// `forwarded` the fields of `#[response(transparent)]` ones.
+fn expand<E: BodyExpander>(
+    ty: &Ident,
+    generics: &Generics,
+    attrs: &Attrs,
+    own: &[&Attrs],
+    forwarded: &[&Field],
+) -> TokenStream {
+    let mut inferred_bounds = InferredBounds::new();
+    for field in forwarded {
+        if field.contains_generic {
+            inferred_bounds.insert(
+                field.ty,
+                quote! { ::actix_web_error::__private::schemars::JsonSchema },
+            );
+        }
+    }
+    let where_clause = inferred_bounds.augment_where_clause(generics);
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let format = E::response_format(attrs);
+    let name = ty.to_string();
+    let codes = own.iter().map(|attrs| code(attrs));
+    let any_code = own.iter().any(|attrs| is_transparent_code(attrs));
+    let schema = quote! {
+        ::actix_web_error::__private::schemars_schema(#format, &[#(#codes),*], #any_code)
+    };
+
+    let json_schema = if forwarded.is_empty() {
+        quote! {
+            fn json_schema(
+                _: &mut ::actix_web_error::__private::schemars::SchemaGenerator,
+            ) -> ::actix_web_error::__private::schemars::Schema {
+                #schema
+            }
+        }
+    } else {
+        let own = if own.is_empty() {
+            quote! { ::std::option::Option::None }
+        } else {
+            quote! { ::std::option::Option::Some(#schema) }
+        };
+        let forwarded = forwarded.iter().map(|field| field.ty);
+        quote! {
+            fn json_schema(
+                generator: &mut ::actix_web_error::__private::schemars::SchemaGenerator,
+            ) -> ::actix_web_error::__private::schemars::Schema {
+                ::actix_web_error::__private::schemars_forwarding_schema(
+                    #own,
+                    ::std::vec![#(
+                        <#forwarded as ::actix_web_error::__private::schemars::JsonSchema>::json_schema(generator)
+                    ),*],
+                )
+            }
+        }
+    };
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::__private::schemars::JsonSchema for #ty #ty_generics #where_clause {
+            fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#name)
+            }
+
+            #json_schema
+        }
+    }
+}
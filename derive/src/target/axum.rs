@@ -0,0 +1,47 @@
+use super::{Impl, Target};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Member;
+
+pub struct Axum;
+
+impl Target for Axum {
+    fn http() -> TokenStream {
+        quote! { ::axum::http }
+    }
+
+    fn struct_field(member: &Member) -> TokenStream {
+        quote! { self.#member }
+    }
+
+    fn transparent_status(field: &TokenStream) -> TokenStream {
+        quote! { ::axum::response::IntoResponse::into_response(#field).status() }
+    }
+
+    fn transparent_bound() -> TokenStream {
+        quote! { ::axum::response::IntoResponse }
+    }
+
+    fn expand_impl(imp: &Impl) -> TokenStream {
+        let Impl {
+            impl_generics,
+            ty,
+            where_clause,
+            prelude,
+            status,
+            body,
+        } = imp;
+
+        quote! {
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::axum::response::IntoResponse for #ty #where_clause {
+                fn into_response(self) -> ::axum::response::Response {
+                    #prelude
+                    #[allow(unused_variables, deprecated, clippy::used_underscore_binding)]
+                    let status: ::axum::http::StatusCode = #status;
+                    ::axum::response::IntoResponse::into_response((status, #body))
+                }
+            }
+        }
+    }
+}
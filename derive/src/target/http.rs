@@ -0,0 +1,64 @@
+use super::{Impl, Target};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Member;
+
+pub struct Http;
+
+impl Target for Http {
+    fn http() -> TokenStream {
+        quote! { ::actix_web_error::__private::http }
+    }
+
+    fn struct_field(member: &Member) -> TokenStream {
+        quote! { &self.#member }
+    }
+
+    fn transparent_status(field: &TokenStream) -> TokenStream {
+        quote! { ::actix_web_error::IntoHttpResponse::status_code(#field) }
+    }
+
+    fn transparent_bound() -> TokenStream {
+        quote! { ::actix_web_error::IntoHttpResponse }
+    }
+
+    fn expand_impl(imp: &Impl) -> TokenStream {
+        let Impl {
+            impl_generics,
+            ty,
+            where_clause,
+            prelude,
+            status,
+            body,
+        } = imp;
+
+        quote! {
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::actix_web_error::IntoHttpResponse for #ty #where_clause {
+                #[allow(unused_variables, deprecated, clippy::used_underscore_binding)]
+                fn status_code(&self) -> ::actix_web_error::__private::http::StatusCode {
+                    #status
+                }
+
+                fn http_response(&self) -> ::actix_web_error::__private::http::Response<::std::vec::Vec<u8>> {
+                    #prelude
+                    let (content_type, body): (&'static str, ::std::vec::Vec<u8>) = #body;
+                    let mut response = ::actix_web_error::__private::http::Response::new(body);
+                    *response.status_mut() = ::actix_web_error::IntoHttpResponse::status_code(self);
+                    response.headers_mut().insert(
+                        ::actix_web_error::__private::http::header::CONTENT_TYPE,
+                        ::actix_web_error::__private::http::HeaderValue::from_static(content_type),
+                    );
+                    response
+                }
+            }
+
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::convert::From<&#ty> for ::actix_web_error::__private::http::Response<::std::vec::Vec<u8>> #where_clause {
+                fn from(error: &#ty) -> Self {
+                    ::actix_web_error::IntoHttpResponse::http_response(error)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Responses for frameworks other than actix-web.
+
+use crate::{
+    attr::{Attrs, ResolveStatus, SourceStatus},
+    expand::{error_code_expr, fields_pat, infer_error_code_bounds},
+    generics::InferredBounds,
+    input::{Enum, Input, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, DeriveInput, Error, Generics, Ident, ImplGenerics, Member, Result, WhereClause,
+};
+
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "axum")]
+pub use self::axum::Axum;
+#[cfg(feature = "http")]
+pub use self::http::Http;
+
+/// A framework the response is generated for.
+pub trait Target {
+    /// Path of the `http` crate used by the framework.
+    fn http() -> TokenStream;
+
+    /// Access to the field `member` of `self` which can be passed to [`Target::transparent_status`].
+    fn struct_field(member: &Member) -> TokenStream;
+
+    /// Status of the `#[status(transparent)]` `field`.
+    fn transparent_status(field: &TokenStream) -> TokenStream;
+
+    /// Bound required for a `#[status(transparent)]` field with generics.
+    fn transparent_bound() -> TokenStream;
+
+    /// The implementation(s) for the error.
+    fn expand_impl(imp: &Impl) -> TokenStream;
+}
+
+/// Parts of the implementation for an error.
+pub struct Impl<'a> {
+    pub impl_generics: ImplGenerics<'a>,
+    /// The error type with generics.
+    pub ty: TokenStream,
+    pub where_clause: WhereClause,
+    /// Statements defining `message` and `code`.
+    pub prelude: TokenStream,
+    /// Expression evaluating to the status (moves out of `self` for transparent fields).
+    ///
+    /// The expression might bind unused variables.
+    pub status: TokenStream,
+    /// The body of the response, see [`BodyExpander`].
+    pub body: TokenStream,
+}
+
+/// Generates the body of a response for `T`.
+///
+/// `message: String` and `code: Option<&'static str>` are in scope.
+pub trait BodyExpander<T: Target> {
+    fn expand_body() -> TokenStream;
+}
+
+pub fn expand<T, E>(node: &DeriveInput) -> Result<TokenStream>
+where
+    T: Target,
+    E: BodyExpander<T>,
+{
+    match Input::from_syn(node)? {
+        Input::Struct(s) => {
+            unsupported_message(&s.attrs)?;
+            Ok(impl_struct::<T, E>(&s))
+        }
+        Input::Enum(e) => {
+            unsupported_message(&e.attrs)?;
+            for variant in &e.variants {
+                unsupported_message(&variant.attrs)?;
+            }
+            Ok(impl_enum::<T, E>(&e))
+        }
+    }
+}
+
+fn impl_struct<T, E>(input: &Struct) -> TokenStream
+where
+    T: Target,
+    E: BodyExpander<T>,
+{
+    let http = T::http();
+
+    let mut inferred_bounds = InferredBounds::new();
+    let status = match &input.attrs.status {
+        Some(ResolveStatus::Transparent(_)) => {
+            let field = &input.fields[0];
+            if field.contains_generic {
+                inferred_bounds.insert(field.ty, T::transparent_bound());
+            }
+            T::transparent_status(&T::struct_field(&field.member))
+        }
+        Some(ResolveStatus::Fixed(status)) => status.code.tokens(&http),
+        Some(ResolveStatus::Source(source)) => unsupported_source(source),
+        None => quote! { #http::StatusCode::INTERNAL_SERVER_ERROR },
+    };
+    infer_error_code_bounds(&input.attrs, &input.fields, &mut inferred_bounds);
+    let code = match &input.attrs.error_code {
+        Some(error_code) => error_code.error_code.tokens(),
+        None => quote! { None },
+    };
+
+    expand_impl::<T, E>(
+        &input.ident,
+        input.generics,
+        &inferred_bounds,
+        quote! {
+            let message = ::std::string::ToString::to_string(&self);
+            let code: ::std::option::Option<&'static str> = #code;
+        },
+        status,
+    )
+}
+
+fn impl_enum<T, E>(input: &Enum) -> TokenStream
+where
+    T: Target,
+    E: BodyExpander<T>,
+{
+    let ty = &input.ident;
+    let http = T::http();
+
+    let mut inferred_bounds = InferredBounds::new();
+    let arms = input.variants.iter().map(|variant| {
+        let status = match &variant.attrs.status {
+            Some(ResolveStatus::Transparent(_)) => {
+                let field = &variant.fields[0];
+                if field.contains_generic {
+                    inferred_bounds.insert(field.ty, T::transparent_bound());
+                }
+                let only_field = match &field.member {
+                    Member::Named(ident) => ident.clone(),
+                    Member::Unnamed(idx) => format_ident!("_{}", idx),
+                };
+                T::transparent_status(&quote! { #only_field })
+            }
+            Some(ResolveStatus::Fixed(status)) => status.code.tokens(&http),
+            Some(ResolveStatus::Source(source)) => unsupported_source(source),
+            None => quote! { #http::StatusCode::INTERNAL_SERVER_ERROR },
+        };
+        let ident = &variant.ident;
+        let pat = fields_pat(&variant.fields);
+        quote! { #ty::#ident #pat => #status }
+    });
+    let arms: Vec<_> = arms.collect();
+    for variant in &input.variants {
+        infer_error_code_bounds(&variant.attrs, &variant.fields, &mut inferred_bounds);
+    }
+    let code = error_code_expr(input, &quote! { &self });
+
+    expand_impl::<T, E>(
+        ty,
+        input.generics,
+        &inferred_bounds,
+        quote! {
+            let message = ::std::string::ToString::to_string(&self);
+            #[allow(unused_variables, clippy::used_underscore_binding)]
+            let code: ::std::option::Option<&'static str> = #code;
+        },
+        quote! {
+            match self {
+                #(#arms,)*
+            }
+        },
+    )
+}
+
+/// Messages are translated by the actix-web `Localize` middleware.
+fn unsupported_message(attrs: &Attrs) -> Result<()> {
+    match &attrs.message_key {
+        Some(key) => Err(Error::new_spanned(
+            key,
+            "#[message(key = ..)] is only supported by the actix-web derives",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The `source()` chain is resolved with the registry of actix-web `ResponseError`s.
+fn unsupported_source(source: &SourceStatus) -> TokenStream {
+    Error::new_spanned(
+        source.original,
+        "#[status(source)] is only supported by the actix-web derives",
+    )
+    .to_compile_error()
+}
+
+fn expand_impl<T, E>(
+    ty: &Ident,
+    generics: &Generics,
+    inferred_bounds: &InferredBounds,
+    prelude: TokenStream,
+    status: TokenStream,
+) -> TokenStream
+where
+    T: Target,
+    E: BodyExpander<T>,
+{
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let ty = quote! { #ty #ty_generics };
+    let mut where_clause = inferred_bounds.augment_where_clause(generics);
+    // The message is created from the `Display` implementation,
+    // which might have bounds on generics (e.g. from thiserror).
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty: ::std::fmt::Display));
+    T::expand_impl(&Impl {
+        impl_generics,
+        ty,
+        where_clause,
+        prelude,
+        status,
+        body: E::expand_body(),
+    })
+}
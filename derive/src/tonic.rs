@@ -0,0 +1,62 @@
+use crate::{
+    expand::error_code_expr,
+    grpc,
+    input::{Enum, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, WhereClause};
+
+/// `From<E> for tonic::Status` for containers with `#[grpc]`, `where_clause` being the one of the `ResponseError` implementation.
+pub fn expand_struct(input: &Struct, where_clause: &WhereClause) -> TokenStream {
+    let status = quote! { ::actix_web::ResponseError::status_code(&error) };
+    let grpc_code = grpc::struct_code(&input.attrs, &status);
+    let code = quote! { ::actix_web_error::ErrorCode::error_code(&error) };
+    expand(
+        &input.ident,
+        input.generics,
+        where_clause,
+        &grpc_code,
+        &code,
+    )
+}
+
+/// See [`expand_struct`].
+pub fn expand_enum(input: &Enum, where_clause: &WhereClause) -> TokenStream {
+    let status = quote! { ::actix_web::ResponseError::status_code(&error) };
+    let grpc_code = grpc::enum_code(input, &quote! { &error }, &status);
+    let code = error_code_expr(input, &quote! { &error });
+    expand(
+        &input.ident,
+        input.generics,
+        where_clause,
+        &grpc_code,
+        &code,
+    )
+}
+
+fn expand(
+    ty: &Ident,
+    generics: &Generics,
+    where_clause: &WhereClause,
+    grpc_code: &TokenStream,
+    code: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::std::convert::From<#ty #ty_generics> for ::actix_web_error::__private::tonic::Status #where_clause {
+            fn from(error: #ty #ty_generics) -> Self {
+                let grpc_code: ::actix_web_error::grpc::GrpcCode = #grpc_code;
+                #[allow(unused_variables, clippy::used_underscore_binding)]
+                let code: ::std::option::Option<&'static str> = #code;
+                ::actix_web_error::__private::tonic_status(
+                    grpc_code,
+                    ::std::string::ToString::to_string(&error),
+                    code,
+                )
+            }
+        }
+    }
+}
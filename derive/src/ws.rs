@@ -0,0 +1,100 @@
+use crate::{
+    attr::{Attrs, ResolveStatus},
+    expand::fields_pat,
+    input::{self, Enum, Field, Struct},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Generics, Ident, WhereClause};
+
+/// `WsClose`, `where_clause` being the one of the `ResponseError` implementation.
+pub fn expand_struct(input: &Struct, where_clause: &WhereClause) -> TokenStream {
+    let mut where_clause = where_clause.clone();
+    let close_code = match transparent_field(&input.attrs, &input.fields) {
+        Some(field) => {
+            infer_bound(field, &mut where_clause);
+            let member = &field.member;
+            quote! { ::actix_web_error::ws::WsClose::close_code(&self.#member) }
+        }
+        None => close_code(&input.attrs, None),
+    };
+    expand(&input.ident, input.generics, &where_clause, &close_code)
+}
+
+/// See [`expand_struct`].
+pub fn expand_enum(input: &Enum, where_clause: &WhereClause) -> TokenStream {
+    let mut where_clause = where_clause.clone();
+    let ty = &input.ident;
+    let arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        match transparent_field(&variant.attrs, &variant.fields) {
+            Some(field) => {
+                infer_bound(field, &mut where_clause);
+                let pat = fields_pat(&variant.fields);
+                let binding = input::binding(&field.member);
+                quote! { #ty::#ident #pat => ::actix_web_error::ws::WsClose::close_code(#binding) }
+            }
+            None => {
+                let close_code = close_code(&variant.attrs, Some(&input.attrs));
+                quote! { #ty::#ident { .. } => #close_code }
+            }
+        }
+    });
+    let arms: Vec<_> = arms.collect();
+    let close_code = quote! {
+        match self {
+            #(#arms,)*
+        }
+    };
+    expand(&input.ident, input.generics, &where_clause, &close_code)
+}
+
+/// The field of a `#[status(transparent)]` struct or variant without its own `#[ws_close(..)]`,
+/// which responds with the close code of the wrapped error.
+fn transparent_field<'a, 'b>(attrs: &Attrs, fields: &'b [Field<'a>]) -> Option<&'b Field<'a>> {
+    match (&attrs.status, attrs.ws_close) {
+        (Some(ResolveStatus::Transparent(_)), None) => fields.first(),
+        _ => None,
+    }
+}
+
+fn infer_bound(field: &Field, where_clause: &mut WhereClause) {
+    if field.contains_generic {
+        let ty = field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::actix_web_error::ws::WsClose));
+    }
+}
+
+/// The `#[ws_close(..)]` of the struct or variant, then the one of the enum, then the default of the status.
+fn close_code(attrs: &Attrs, parent: Option<&Attrs>) -> TokenStream {
+    match attrs
+        .ws_close
+        .or_else(|| parent.and_then(|parent| parent.ws_close))
+    {
+        Some(code) => quote! { ::actix_web_error::ws::CloseCode::from(#code) },
+        None => quote! {
+            ::actix_web_error::ws::default_close_code(::actix_web::ResponseError::status_code(self))
+        },
+    }
+}
+
+fn expand(
+    ty: &Ident,
+    generics: &Generics,
+    where_clause: &WhereClause,
+    close_code: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    quote! {
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::actix_web_error::ws::WsClose for #ty #ty_generics #where_clause {
+            #[allow(clippy::used_underscore_binding)]
+            fn close_code(&self) -> ::actix_web_error::ws::CloseCode {
+                #close_code
+            }
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use proc_macro2::{Group, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Attribute, Error, Ident, LitInt, LitStr, Result, Token,
+};
+
+pub struct Attrs<'a> {
+    pub status: Option<ResolveStatus<'a>>,
+    pub error_code: Option<ErrorCode<'a>>,
+    /// The format string of thiserror's `#[error("..")]`.
+    pub display: Option<LitStr>,
+    /// The translation key from `#[message(key = "..")]`.
+    pub message_key: Option<LitStr>,
+    /// The template function from `#[html(template = ..)]`.
+    pub html_template: Option<syn::Path>,
+    /// The `source` of JSON:API errors from `#[json_api(pointer = "..")]` or `#[json_api(parameter)]`.
+    pub json_api_source: Option<JsonApiSource<'a>>,
+    /// `#[grpc]` or `#[grpc(code = ..)]`, on a container opting into `From<E> for tonic::Status`.
+    pub grpc: Option<&'a Attribute>,
+    /// The gRPC code from `#[grpc(code = ..)]`, a variant of `GrpcCode`.
+    pub grpc_code: Option<Ident>,
+    /// `#[detail]` on a field, added to GraphQL error extensions.
+    pub detail: Option<&'a Attribute>,
+    /// The WebSocket close code from `#[ws_close(..)]`.
+    pub ws_close: Option<u16>,
+    /// `#[decode]` on a `Json` container, generating `from_response` and `TryFrom<ErrorBody>`.
+    pub decode: Option<&'a Attribute>,
+    /// `#[response(transparent)]`, implying `#[status(transparent)]` and `#[error_code(transparent)]`.
+    pub response_transparent: Option<&'a Attribute>,
+}
+
+#[derive(Clone)]
+pub enum ResolveStatus<'a> {
+    Transparent(&'a Attribute),
+    Fixed(Status<'a>),
+    Source(SourceStatus<'a>),
+}
+
+/// `#[status(source)]`: the status of the first registered error in the `source()` chain.
+#[derive(Clone)]
+pub struct SourceStatus<'a> {
+    pub original: &'a Attribute,
+    /// The status of the enum for variants, used if no registered error is found.
+    pub fallback: Option<StatusCode>,
+}
+
+#[derive(Clone)]
+pub struct Status<'a> {
+    pub original: &'a Attribute,
+    pub code: StatusCode,
+}
+
+#[derive(Clone)]
+pub enum StatusCode {
+    Value(http::StatusCode),
+    Name(Ident),
+}
+
+#[derive(Clone)]
+pub struct ErrorCode<'a> {
+    pub original: &'a Attribute,
+    pub error_code: Code,
+}
+
+#[derive(Clone)]
+pub enum Code {
+    String(String),
+    Name(Group),
+    /// `#[error_code(transparent)]` with the expression referencing the only field
+    /// (filled in by `input`).
+    Transparent(TokenStream),
+}
+
+#[derive(Clone)]
+pub struct JsonApiSource<'a> {
+    pub original: &'a Attribute,
+    pub kind: SourceKind,
+    /// `None` on fields, the value of the field is used then.
+    pub value: Option<LitStr>,
+}
+
+#[derive(Clone, Copy)]
+pub enum SourceKind {
+    Pointer,
+    Parameter,
+}
+
+mod kw {
+    syn::custom_keyword!(transparent);
+    syn::custom_keyword!(source);
+    syn::custom_keyword!(key);
+    syn::custom_keyword!(template);
+    syn::custom_keyword!(pointer);
+    syn::custom_keyword!(parameter);
+    syn::custom_keyword!(code);
+}
+
+impl StatusCode {
+    /// Tokens evaluating to this status code, `http` being the path of the `http` crate to use.
+    pub fn tokens(&self, http: &TokenStream) -> TokenStream {
+        match self {
+            StatusCode::Value(v) => {
+                let value = v.as_u16();
+                quote! { #http::StatusCode::from_u16(#value).unwrap() }
+            }
+            StatusCode::Name(ident) => quote! { #http::StatusCode::#ident },
+        }
+    }
+}
+
+impl ToTokens for StatusCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.tokens(&quote! { ::actix_web::http }));
+    }
+}
+
+impl ToTokens for Status<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.code.to_tokens(tokens);
+    }
+}
+
+impl Attrs<'_> {
+    pub fn get(input: &[Attribute]) -> Result<Attrs<'_>> {
+        let mut attrs = Attrs {
+            status: None,
+            error_code: None,
+            display: None,
+            message_key: None,
+            html_template: None,
+            json_api_source: None,
+            grpc: None,
+            grpc_code: None,
+            detail: None,
+            ws_close: None,
+            decode: None,
+            response_transparent: None,
+        };
+
+        for attr in input {
+            if attr.path.is_ident("status") {
+                attrs.parse_status_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("error_code") {
+                attrs.parse_error_code_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("message") {
+                attrs.parse_message_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("html") {
+                attrs.parse_html_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("json_api") {
+                attrs.parse_json_api_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("grpc") {
+                attrs.parse_grpc_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("detail") {
+                attrs.parse_detail_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("ws_close") {
+                attrs.parse_ws_close_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("decode") {
+                attrs.parse_decode_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("response") {
+                attrs.parse_response_attribute(attr)?;
+            }
+
+            if attr.path.is_ident("error") {
+                attrs.display = parse_display_attribute(attr);
+            }
+        }
+
+        if let Some(attr) = attrs.response_transparent {
+            if attrs.status.is_some() || attrs.error_code.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "#[response(transparent)] can't be combined with #[status(..)] or #[error_code(..)]",
+                ));
+            }
+            attrs.status = Some(ResolveStatus::Transparent(attr));
+            attrs.error_code = Some(ErrorCode {
+                original: attr,
+                error_code: Code::Transparent(TokenStream::new()),
+            });
+        }
+
+        Ok(attrs)
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        self.status.as_ref().map(|st| match st {
+            ResolveStatus::Transparent(t) => t.span(),
+            ResolveStatus::Fixed(fix) => fix.original.span(),
+            ResolveStatus::Source(source) => source.original.span(),
+        })
+    }
+}
+
+impl<'a> Attrs<'a> {
+    fn parse_status_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.status.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[status(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            if input.parse::<Option<kw::transparent>>()?.is_some() {
+                self.status = Some(ResolveStatus::Transparent(attr));
+                return Ok(());
+            }
+            if input.parse::<Option<kw::source>>()?.is_some() {
+                self.status = Some(ResolveStatus::Source(SourceStatus {
+                    original: attr,
+                    fallback: None,
+                }));
+                return Ok(());
+            }
+
+            let status = Status {
+                original: attr,
+                code: parse_status_expr(input)?,
+            };
+            self.status = Some(ResolveStatus::Fixed(status));
+            Ok(())
+        })
+    }
+
+    fn parse_error_code_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.error_code.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[error_code(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            let error_code = ErrorCode {
+                original: attr,
+                error_code: parse_error_code_expr(input)?,
+            };
+            self.error_code = Some(error_code);
+            Ok(())
+        })
+    }
+
+    fn parse_message_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.message_key.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[message(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            input.parse::<kw::key>()?;
+            input.parse::<Token![=]>()?;
+            self.message_key = Some(input.parse()?);
+            Ok(())
+        })
+    }
+
+    fn parse_html_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.html_template.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[html(..)] attribute"));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            input.parse::<kw::template>()?;
+            input.parse::<Token![=]>()?;
+            self.html_template = Some(input.parse()?);
+            Ok(())
+        })
+    }
+
+    fn parse_json_api_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.json_api_source.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[json_api(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            let lookahead = input.lookahead1();
+            let kind = if lookahead.peek(kw::pointer) {
+                input.parse::<kw::pointer>()?;
+                SourceKind::Pointer
+            } else if lookahead.peek(kw::parameter) {
+                input.parse::<kw::parameter>()?;
+                SourceKind::Parameter
+            } else {
+                return Err(lookahead.error());
+            };
+            let value = if input.parse::<Option<Token![=]>>()?.is_some() {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            self.json_api_source = Some(JsonApiSource {
+                original: attr,
+                kind,
+                value,
+            });
+            Ok(())
+        })
+    }
+
+    fn parse_response_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.response_transparent.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[response(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            input.parse::<kw::transparent>()?;
+            self.response_transparent = Some(attr);
+            Ok(())
+        })
+    }
+
+    fn parse_detail_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.detail.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[detail] attribute"));
+        }
+        if !attr.tokens.is_empty() {
+            return Err(Error::new_spanned(attr, "expected #[detail]"));
+        }
+        self.detail = Some(attr);
+        Ok(())
+    }
+
+    fn parse_decode_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.decode.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[decode] attribute"));
+        }
+        if !attr.tokens.is_empty() {
+            return Err(Error::new_spanned(attr, "expected #[decode]"));
+        }
+        self.decode = Some(attr);
+        Ok(())
+    }
+
+    fn parse_ws_close_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.ws_close.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate #[ws_close(..)] attribute",
+            ));
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            let lit = input.parse::<LitInt>()?;
+            let code = lit.base10_parse::<u16>()?;
+            // 1004-1006 and 1015 are reserved for endpoints, 1016-2999 for future protocol versions
+            if !matches!(code, 1000..=1003 | 1007..=1014 | 3000..=4999) {
+                return Err(Error::new_spanned(
+                    lit,
+                    "close codes have to be in 1000..=1003, 1007..=1014 or 3000..=4999",
+                ));
+            }
+            self.ws_close = Some(code);
+            Ok(())
+        })
+    }
+
+    fn parse_grpc_attribute(&mut self, attr: &'a Attribute) -> Result<()> {
+        if self.grpc.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[grpc(..)] attribute"));
+        }
+        self.grpc = Some(attr);
+        if attr.tokens.is_empty() {
+            return Ok(());
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            input.parse::<kw::code>()?;
+            input.parse::<Token![=]>()?;
+            self.grpc_code = Some(input.parse()?);
+            Ok(())
+        })
+    }
+}
+
+/// The attribute is validated by thiserror, so this doesn't report any errors.
+fn parse_display_attribute(attr: &Attribute) -> Option<LitStr> {
+    attr.parse_args_with(|input: ParseStream| {
+        let display = input.parse::<Option<LitStr>>()?;
+        input.parse::<TokenStream>()?;
+        Ok(display)
+    })
+    .ok()
+    .flatten()
+}
+
+fn parse_status_expr(input: ParseStream) -> Result<StatusCode> {
+    match input.parse::<Option<LitInt>>()? {
+        Some(lit) => http::status::StatusCode::from_u16(lit.base10_parse::<u16>()?)
+            .map_err(|e| Error::new_spanned(lit.token(), e))
+            .map(StatusCode::Value),
+        None => input.parse::<Ident>().map(StatusCode::Name),
+    }
+}
+
+fn parse_error_code_expr(input: ParseStream) -> Result<Code> {
+    if input.parse::<Option<kw::transparent>>()?.is_some() {
+        Ok(Code::Transparent(TokenStream::new()))
+    } else if let Ok(string) = input.parse::<LitStr>() {
+        Ok(Code::String(string.value()))
+    } else {
+        let ident = input.parse::<Group>()?;
+        Ok(Code::Name(ident))
+    }
+}
+
+impl Code {
+    pub fn tokens(&self) -> TokenStream {
+        match self {
+            Code::String(string) => quote! { Some(#string) },
+            Code::Name(ident) => quote! { #ident },
+            Code::Transparent(field) => quote! { ::actix_web_error::ErrorCode::error_code(#field) },
+        }
+    }
+
+    /// Tokens evaluating to the code where no error is available (`None` for transparent codes).
+    pub fn static_tokens(&self) -> TokenStream {
+        match self {
+            Code::Transparent(_) => quote! { ::std::option::Option::None },
+            code => code.tokens(),
+        }
+    }
+}
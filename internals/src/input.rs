@@ -0,0 +1,244 @@
+use crate::{
+    attr::{Attrs, Code, ResolveStatus},
+    generics::TypeParams,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Generics, Ident, Index, Member, Result,
+    Type,
+};
+
+pub enum Input<'a> {
+    Struct(Struct<'a>),
+    Enum(Enum<'a>),
+}
+
+pub struct Struct<'a> {
+    pub attrs: Attrs<'a>,
+    pub ident: Ident,
+    pub generics: &'a Generics,
+    pub fields: Vec<Field<'a>>,
+}
+
+pub struct Enum<'a> {
+    pub attrs: Attrs<'a>,
+    pub ident: Ident,
+    pub generics: &'a Generics,
+    pub variants: Vec<Variant<'a>>,
+}
+
+pub struct Variant<'a> {
+    pub attrs: Attrs<'a>,
+    pub ident: Ident,
+    pub fields: Vec<Field<'a>>,
+}
+
+pub struct Field<'a> {
+    pub original: &'a syn::Field,
+    pub attrs: Attrs<'a>,
+    pub member: Member,
+    pub ty: &'a Type,
+    pub contains_generic: bool,
+}
+
+impl<'a> Input<'a> {
+    pub fn from_syn(node: &'a DeriveInput) -> Result<Self> {
+        match &node.data {
+            Data::Struct(data) => Struct::from_syn(node, data).map(Input::Struct),
+            Data::Enum(data) => Enum::from_syn(node, data).map(Input::Enum),
+            Data::Union(_) => Err(Error::new_spanned(
+                node,
+                "union as errors are not supported",
+            )),
+        }
+    }
+}
+
+impl<'a> Struct<'a> {
+    fn from_syn(node: &'a DeriveInput, data: &'a DataStruct) -> Result<Self> {
+        let mut attrs = Attrs::get(&node.attrs)?;
+        let scope = TypeParams::new(&node.generics);
+        let span = attrs.span().unwrap_or_else(Span::call_site);
+        let fields = Field::multiple_from_syn(&data.fields, &scope, span)?;
+        validate_source(&attrs, &fields)?;
+        resolve_transparent(&mut attrs, &fields, |member| quote! { &self.#member })?;
+        Ok(Struct {
+            attrs,
+            ident: node.ident.clone(),
+            generics: &node.generics,
+            fields,
+        })
+    }
+}
+
+impl<'a> Enum<'a> {
+    fn from_syn(node: &'a DeriveInput, data: &'a DataEnum) -> Result<Self> {
+        let attrs = Attrs::get(&node.attrs)?;
+        if let Some(attr) = attrs.response_transparent.or_else(|| {
+            attrs
+                .error_code
+                .as_ref()
+                .and_then(|code| match code.error_code {
+                    Code::Transparent(_) => Some(code.original),
+                    _ => None,
+                })
+        }) {
+            return Err(Error::new_spanned(
+                attr,
+                "transparent responses and error codes are only supported on structs and variants",
+            ));
+        }
+        let scope = TypeParams::new(&node.generics);
+        let span = attrs.span().unwrap_or_else(Span::call_site);
+        let variants = data
+            .variants
+            .iter()
+            .map(|node| {
+                let mut variant = Variant::from_syn(node, &scope, span)?;
+                match (&mut variant.attrs.status, &attrs.status) {
+                    (display @ None, _) => display.clone_from(&attrs.status),
+                    (Some(ResolveStatus::Source(source)), Some(ResolveStatus::Fixed(status))) => {
+                        source.fallback = Some(status.code.clone());
+                    }
+                    _ => (),
+                }
+                validate_source(&variant.attrs, &variant.fields)?;
+                Ok(variant)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Enum {
+            attrs,
+            ident: node.ident.clone(),
+            generics: &node.generics,
+            variants,
+        })
+    }
+}
+
+impl<'a> Variant<'a> {
+    fn from_syn(node: &'a syn::Variant, scope: &TypeParams<'a>, span: Span) -> Result<Self> {
+        let mut attrs = Attrs::get(&node.attrs)?;
+        let span = attrs.span().unwrap_or(span);
+        let fields = Field::multiple_from_syn(&node.fields, scope, span)?;
+        resolve_transparent(&mut attrs, &fields, |member| {
+            let binding = binding(member);
+            quote! { #binding }
+        })?;
+        Ok(Variant {
+            attrs,
+            ident: node.ident.clone(),
+            fields,
+        })
+    }
+}
+
+impl<'a> Field<'a> {
+    fn multiple_from_syn(
+        fields: &'a Fields,
+        scope: &TypeParams<'a>,
+        span: Span,
+    ) -> Result<Vec<Self>> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| Field::from_syn(i, field, scope, span))
+            .collect()
+    }
+
+    fn from_syn(
+        i: usize,
+        node: &'a syn::Field,
+        scope: &TypeParams<'a>,
+        span: Span,
+    ) -> Result<Self> {
+        Ok(Field {
+            original: node,
+            attrs: Attrs::get(&node.attrs)?,
+            member: node.ident.clone().map_or_else(
+                || {
+                    Member::Unnamed(Index {
+                        index: i.try_into().unwrap(),
+                        span,
+                    })
+                },
+                Member::Named,
+            ),
+            ty: &node.ty,
+            contains_generic: scope.intersects(&node.ty),
+        })
+    }
+}
+
+/// The field passed to `#[status(source)]`:
+/// the one marked with `#[source]` or `#[from]` (or named `source`), otherwise the only field.
+pub fn source_field<'b, 'a>(fields: &'b [Field<'a>]) -> Option<&'b Field<'a>> {
+    fields
+        .iter()
+        .find(|field| {
+            field
+                .original
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("source") || attr.path.is_ident("from"))
+                || matches!(&field.member, Member::Named(ident) if ident == "source")
+        })
+        .or(match fields {
+            [only] => Some(only),
+            _ => None,
+        })
+}
+
+/// The field a `#[response(transparent)]` struct or variant forwards its response to.
+pub fn forwarded_field<'b, 'a>(attrs: &Attrs, fields: &'b [Field<'a>]) -> Option<&'b Field<'a>> {
+    attrs.response_transparent.and(fields.first())
+}
+
+fn validate_source(attrs: &Attrs, fields: &[Field]) -> Result<()> {
+    match &attrs.status {
+        Some(ResolveStatus::Source(source)) if source_field(fields).is_none() => {
+            Err(Error::new_spanned(
+                source.original,
+                "#[status(source)] requires a #[source] or #[from] field or exactly one field",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that transparent attributes are used with a single field
+/// and points `#[error_code(transparent)]` to it, `access` being the expression referencing the field.
+fn resolve_transparent(
+    attrs: &mut Attrs,
+    fields: &[Field],
+    access: impl FnOnce(&Member) -> TokenStream,
+) -> Result<()> {
+    let transparent_code = match &mut attrs.error_code {
+        Some(code) => match &mut code.error_code {
+            Code::Transparent(field) => Some((code.original, field)),
+            _ => None,
+        },
+        None => None,
+    };
+    let Some((original, field)) = transparent_code else {
+        return Ok(());
+    };
+    match fields {
+        [only] => {
+            *field = access(&only.member);
+            Ok(())
+        }
+        _ => Err(Error::new_spanned(
+            original,
+            "transparent error codes and responses require exactly one field",
+        )),
+    }
+}
+
+/// The variable a field of a variant is bound to by `expand::fields_pat`.
+pub fn binding(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(idx) => format_ident!("_{}", idx),
+    }
+}
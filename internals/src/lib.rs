@@ -0,0 +1,16 @@
+//! Parsing of the attributes of [actix-web-error](https://docs.rs/actix-web-error)'s derives,
+//! shared by `actix-web-error-derive` and `actix-web-error-catalog`.
+//!
+//! This is an implementation detail of these crates and has no stable API.
+
+#![warn(clippy::pedantic)]
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::must_use_candidate,
+    clippy::new_without_default
+)]
+
+pub mod attr;
+pub mod generics;
+pub mod input;
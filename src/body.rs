@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// The body of a [`Json`](crate::Json) (or [`Xml`](crate::Xml)) error response.
+///
+/// Clients can deserialize responses into this and convert them back into the
+/// derived error (marked with `#[decode]`) with `TryFrom<ErrorBody>` or `E::from_response(status, body)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+// the root element of `Xml` responses
+#[cfg_attr(feature = "utoipa", schema(xml(name = "error")))]
+pub struct ErrorBody {
+    /// The [`Display`](std::fmt::Display) representation of the error.
+    pub message: String,
+    /// The error code if the error has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
@@ -0,0 +1,5 @@
+/// Access to the `#[error_code(..)]` of an error, implemented by the derives.
+pub trait ErrorCode {
+    /// The error code of the struct or variant, if it has one.
+    fn error_code(&self) -> Option<&'static str>;
+}
@@ -0,0 +1,68 @@
+//! JSON responses for errors produced by actix-web itself.
+//!
+//! Unmatched routes and wrong methods are answered by actix-web with an empty body.
+//! [`default_service`] and [`error_handlers`] render those responses in the same format as [`Json`](crate::Json).
+//!
+//! ```
+//! use actix_web::{web, App};
+//!
+//! let app = App::new()
+//!     .wrap(actix_web_error::fallback::error_handlers())
+//!     .default_service(web::to(actix_web_error::fallback::default_service));
+//! ```
+
+use crate::__private::JsonErrorSerialize;
+use actix_web::{
+    body::{BodySize, BoxBody, EitherBody, MessageBody},
+    dev::ServiceResponse,
+    http::{
+        header::{self, HeaderValue},
+        StatusCode,
+    },
+    middleware::{ErrorHandlerResponse, ErrorHandlers},
+    HttpResponse, Result,
+};
+
+/// Responds with `404 Not Found` and a JSON body.
+///
+/// Meant to be used as the [`default_service`](actix_web::App::default_service) of an app or scope.
+#[allow(clippy::unused_async)]
+pub async fn default_service() -> HttpResponse {
+    HttpResponse::NotFound().json(reason_body(StatusCode::NOT_FOUND))
+}
+
+/// Creates [`ErrorHandlers`] replacing bodiless `4xx`/`5xx` responses with a JSON body.
+///
+/// The message is the canonical reason of the status code (e.g. `Method Not Allowed`).
+/// Responses which already have a body are passed through unchanged.
+#[must_use]
+pub fn error_handlers<B: MessageBody + 'static>() -> ErrorHandlers<B> {
+    ErrorHandlers::new().default_handler(render_bodiless)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn render_bodiless<B: MessageBody>(res: ServiceResponse<B>) -> Result<ErrorHandlerResponse<B>> {
+    if !matches!(
+        res.response().body().size(),
+        BodySize::None | BodySize::Sized(0)
+    ) {
+        return Ok(ErrorHandlerResponse::Response(res.map_into_left_body()));
+    }
+
+    let body = crate::format::json_body(&reason_body(res.status()));
+    let res = res.map_body(|head, _| {
+        head.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        EitherBody::right(BoxBody::new(body))
+    });
+    Ok(ErrorHandlerResponse::Response(res))
+}
+
+pub(crate) fn reason_body(status: StatusCode) -> JsonErrorSerialize<'static, str> {
+    JsonErrorSerialize {
+        message: status.canonical_reason().unwrap_or("Unknown Error"),
+        code: None,
+    }
+}
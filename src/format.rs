@@ -0,0 +1,121 @@
+use crate::{
+    __private::JsonErrorSerialize,
+    google::GoogleErrorBody,
+    grpc::GrpcCode,
+    html::ErrorPage,
+    json_api::{self, JsonApiDocument, JsonApiError},
+};
+use actix_web::http::StatusCode;
+use std::fmt::Write;
+
+/// Format of the response body of a derive.
+#[derive(Debug, Clone, Copy)]
+pub enum ResponseFormat {
+    Json,
+    Text,
+    /// An HTML page rendered with the template.
+    Html(fn(&ErrorPage) -> String),
+    /// `<error><message>..</message><code>..</code></error>`
+    Xml,
+    /// A JSON:API document with a single error.
+    JsonApi,
+    /// A Google API error with the gRPC code, derived from the status if it's `None`.
+    Google(Option<GrpcCode>),
+    /// The structure of `Json` as a [MessagePack](https://msgpack.org) map.
+    #[cfg(feature = "msgpack")]
+    MsgPack,
+    /// The structure of `Json` as a CBOR map.
+    #[cfg(feature = "cbor")]
+    Cbor,
+}
+
+impl ResponseFormat {
+    #[cfg_attr(not(feature = "utoipa"), allow(dead_code))]
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            ResponseFormat::Json => "application/json",
+            ResponseFormat::Text => "text/plain; charset=utf-8",
+            ResponseFormat::Html(_) => "text/html; charset=utf-8",
+            ResponseFormat::Xml => "application/xml",
+            ResponseFormat::JsonApi => json_api::CONTENT_TYPE,
+            ResponseFormat::Google(_) => "application/json",
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => "application/msgpack",
+            #[cfg(feature = "cbor")]
+            ResponseFormat::Cbor => "application/cbor",
+        }
+    }
+
+    /// The body of a response with `message` and `code` as produced by the derive.
+    #[must_use]
+    pub fn render(self, status: StatusCode, message: &str, code: Option<&str>) -> Vec<u8> {
+        match self {
+            ResponseFormat::Json => json_body(&JsonErrorSerialize { message, code }),
+            ResponseFormat::Text => message.as_bytes().to_vec(),
+            ResponseFormat::Html(template) => {
+                template(&ErrorPage::new(status, message, code)).into_bytes()
+            }
+            ResponseFormat::Xml => {
+                let mut xml = format!("<error><message>{}</message>", xml_escape(message));
+                if let Some(code) = code {
+                    let _ = write!(xml, "<code>{}</code>", xml_escape(code));
+                }
+                xml.push_str("</error>");
+                xml.into_bytes()
+            }
+            ResponseFormat::JsonApi => json_body(&JsonApiDocument::from(JsonApiError::new(
+                status,
+                message.to_owned(),
+                code,
+                None,
+            ))),
+            ResponseFormat::Google(grpc_code) => json_body(&GoogleErrorBody::new(
+                status,
+                grpc_code.unwrap_or_else(|| GrpcCode::from_http_status(status)),
+                message.to_owned(),
+                code,
+            )),
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => msgpack_body(&JsonErrorSerialize { message, code }),
+            #[cfg(feature = "cbor")]
+            ResponseFormat::Cbor => cbor_body(&JsonErrorSerialize { message, code }),
+        }
+    }
+}
+
+/// Serializes an error body, which can't fail as it only contains strings, numbers and JSON values.
+pub(crate) fn json_body(body: &impl serde::Serialize) -> Vec<u8> {
+    serde_json::to_vec(body).expect("serializing strings can't fail")
+}
+
+#[cfg(feature = "msgpack")]
+fn msgpack_body(body: &JsonErrorSerialize<'_, str>) -> Vec<u8> {
+    rmp_serde::to_vec_named(body).expect("serializing strings can't fail")
+}
+
+#[cfg(feature = "cbor")]
+fn cbor_body(body: &JsonErrorSerialize<'_, str>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(body, &mut bytes).expect("writing to a `Vec` can't fail");
+    bytes
+}
+
+/// Escapes `text` for XML content.
+///
+/// Control characters can't be represented in XML 1.0, so they're replaced with U+FFFD.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => escaped.push(char::REPLACEMENT_CHARACTER),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
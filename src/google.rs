@@ -0,0 +1,88 @@
+//! The [Google API error model](https://cloud.google.com/apis/design/errors#http_mapping) of the `GoogleJson` derive.
+//!
+//! ```json
+//! {
+//!   "error": {
+//!     "code": 404,
+//!     "status": "NOT_FOUND",
+//!     "message": "Book not found",
+//!     "details": [
+//!       { "@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "book_not_found" }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! The `status` is derived from the HTTP status (see [`GrpcCode::from_http_status`])
+//! unless it's set with `#[grpc(code = NotFound)]`.
+//! The error code is reported as the `reason` of an `ErrorInfo` detail.
+//!
+//! ```
+//! #[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+//! #[status(NOT_FOUND)]
+//! enum BookError {
+//!     #[error("Book not found")]
+//!     #[error_code("book_not_found")]
+//!     NotFound,
+//!     #[error("The book is borrowed")]
+//!     #[status(409)]
+//!     #[grpc(code = Aborted)] // instead of ALREADY_EXISTS
+//!     Borrowed,
+//! }
+//! ```
+
+use crate::grpc::GrpcCode;
+use actix_web::http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// The type URL of `google.rpc.ErrorInfo` details.
+pub const ERROR_INFO_TYPE: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
+/// The body of a Google API error response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GoogleErrorBody {
+    #[cfg_attr(feature = "utoipa", schema(inline))]
+    pub error: GoogleError,
+}
+
+/// The `error` of a [`GoogleErrorBody`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GoogleError {
+    /// The HTTP status code.
+    pub code: u16,
+    /// The name of the gRPC code.
+    pub status: String,
+    /// The [`Display`](std::fmt::Display) representation of the error.
+    pub message: String,
+    /// Typed details, each with an `@type`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
+    pub details: Vec<serde_json::Value>,
+}
+
+impl GoogleErrorBody {
+    /// The body for an error with the HTTP `status` and the gRPC `grpc_code`.
+    ///
+    /// If the error has a `code`, it's added as an `ErrorInfo` detail.
+    #[must_use]
+    pub fn new(
+        status: StatusCode,
+        grpc_code: GrpcCode,
+        message: String,
+        code: Option<&str>,
+    ) -> Self {
+        Self {
+            error: GoogleError {
+                code: status.as_u16(),
+                status: grpc_code.name().to_owned(),
+                message,
+                details: code
+                    .map(|code| serde_json::json!({ "@type": ERROR_INFO_TYPE, "reason": code }))
+                    .into_iter()
+                    .collect(),
+            },
+        }
+    }
+}
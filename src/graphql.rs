@@ -0,0 +1,30 @@
+use actix_web::http::StatusCode;
+use async_graphql::{Error, ErrorExtensionValues};
+use serde::Serialize;
+
+/// The GraphQL error of a derived error with the `status` and `code` extensions.
+#[must_use]
+pub fn graphql_error(message: String, status: StatusCode, code: Option<&str>) -> Error {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("status", status.as_u16());
+    if let Some(code) = code {
+        extensions.set("code", code);
+    }
+    Error {
+        message,
+        source: None,
+        extensions: Some(extensions),
+    }
+}
+
+/// Adds the `#[detail]` field `name` to the extensions of `error`.
+///
+/// Values that can't be represented as a GraphQL value are skipped.
+pub fn set_detail<T: Serialize + ?Sized>(error: &mut Error, name: &str, value: &T) {
+    if let Ok(value) = async_graphql::to_value(value) {
+        error
+            .extensions
+            .get_or_insert_with(ErrorExtensionValues::default)
+            .set(name, value);
+    }
+}
@@ -0,0 +1,174 @@
+//! Canonical gRPC status codes and their mapping from HTTP status codes.
+//!
+//! With the `tonic` feature, the derives of types with `#[grpc]` or `#[grpc(code = ..)]`
+//! implement `From<E> for tonic::Status`.
+//! The code is derived from the HTTP status (see [`GrpcCode::from_http_status`])
+//! unless it's set with `#[grpc(code = ..)]`, the message is the [`Display`](std::fmt::Display) representation
+//! and the error code is set in the [`ERROR_CODE_METADATA`] metadata.
+//!
+//! ```
+//! # #[cfg(feature = "tonic")]
+//! # {
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[status(NOT_FOUND)]
+//! #[grpc]
+//! enum UserError {
+//!     #[error("User not found")]
+//!     #[error_code("user_not_found")]
+//!     NotFound,
+//!     #[error("User is locked")]
+//!     #[status(409)]
+//!     #[grpc(code = FailedPrecondition)]
+//!     Locked,
+//! }
+//!
+//! let status = tonic::Status::from(UserError::Locked);
+//! assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+//! # }
+//! ```
+
+use actix_web::http::StatusCode;
+use std::fmt;
+
+/// The metadata key of the error code in a `tonic::Status`.
+pub const ERROR_CODE_METADATA: &str = "error-code";
+
+/// A canonical gRPC status code (`google.rpc.Code`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrpcCode {
+    Ok = 0,
+    Cancelled = 1,
+    Unknown = 2,
+    InvalidArgument = 3,
+    DeadlineExceeded = 4,
+    NotFound = 5,
+    AlreadyExists = 6,
+    PermissionDenied = 7,
+    ResourceExhausted = 8,
+    FailedPrecondition = 9,
+    Aborted = 10,
+    OutOfRange = 11,
+    Unimplemented = 12,
+    Internal = 13,
+    Unavailable = 14,
+    DataLoss = 15,
+    Unauthenticated = 16,
+}
+
+impl GrpcCode {
+    /// The gRPC code corresponding to an HTTP status.
+    ///
+    /// | HTTP status      | gRPC code             |
+    /// |------------------|-----------------------|
+    /// | 2xx              | `OK`                  |
+    /// | 400, 422         | `INVALID_ARGUMENT`    |
+    /// | 401              | `UNAUTHENTICATED`     |
+    /// | 403              | `PERMISSION_DENIED`   |
+    /// | 404, 410         | `NOT_FOUND`           |
+    /// | 405, 501         | `UNIMPLEMENTED`       |
+    /// | 408, 504         | `DEADLINE_EXCEEDED`   |
+    /// | 409              | `ALREADY_EXISTS`      |
+    /// | 416              | `OUT_OF_RANGE`        |
+    /// | 429              | `RESOURCE_EXHAUSTED`  |
+    /// | 499              | `CANCELLED`           |
+    /// | 502, 503         | `UNAVAILABLE`         |
+    /// | other 4xx        | `FAILED_PRECONDITION` |
+    /// | other 5xx        | `INTERNAL`            |
+    /// | others           | `UNKNOWN`             |
+    #[must_use]
+    pub fn from_http_status(status: StatusCode) -> Self {
+        match status.as_u16() {
+            200..=299 => Self::Ok,
+            400 | 422 => Self::InvalidArgument,
+            401 => Self::Unauthenticated,
+            403 => Self::PermissionDenied,
+            404 | 410 => Self::NotFound,
+            405 | 501 => Self::Unimplemented,
+            408 | 504 => Self::DeadlineExceeded,
+            409 => Self::AlreadyExists,
+            416 => Self::OutOfRange,
+            429 => Self::ResourceExhausted,
+            499 => Self::Cancelled,
+            502 | 503 => Self::Unavailable,
+            400..=499 => Self::FailedPrecondition,
+            500..=599 => Self::Internal,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// The name of the code, e.g. `NOT_FOUND`.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Cancelled => "CANCELLED",
+            Self::Unknown => "UNKNOWN",
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            Self::NotFound => "NOT_FOUND",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Self::FailedPrecondition => "FAILED_PRECONDITION",
+            Self::Aborted => "ABORTED",
+            Self::OutOfRange => "OUT_OF_RANGE",
+            Self::Unimplemented => "UNIMPLEMENTED",
+            Self::Internal => "INTERNAL",
+            Self::Unavailable => "UNAVAILABLE",
+            Self::DataLoss => "DATA_LOSS",
+            Self::Unauthenticated => "UNAUTHENTICATED",
+        }
+    }
+
+    /// All codes in numeric order.
+    pub const ALL: [GrpcCode; 17] = [
+        Self::Ok,
+        Self::Cancelled,
+        Self::Unknown,
+        Self::InvalidArgument,
+        Self::DeadlineExceeded,
+        Self::NotFound,
+        Self::AlreadyExists,
+        Self::PermissionDenied,
+        Self::ResourceExhausted,
+        Self::FailedPrecondition,
+        Self::Aborted,
+        Self::OutOfRange,
+        Self::Unimplemented,
+        Self::Internal,
+        Self::Unavailable,
+        Self::DataLoss,
+        Self::Unauthenticated,
+    ];
+}
+
+impl fmt::Display for GrpcCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<GrpcCode> for i32 {
+    fn from(code: GrpcCode) -> Self {
+        code as i32
+    }
+}
+
+#[cfg(feature = "tonic")]
+impl From<GrpcCode> for tonic::Code {
+    fn from(code: GrpcCode) -> Self {
+        tonic::Code::from_i32(code.into())
+    }
+}
+
+/// The `tonic::Status` of a derived error.
+#[cfg(feature = "tonic")]
+#[doc(hidden)]
+#[must_use]
+pub fn tonic_status(code: GrpcCode, message: String, error_code: Option<&str>) -> tonic::Status {
+    let mut status = tonic::Status::new(code.into(), message);
+    if let Some(value) = error_code.and_then(|code| code.parse().ok()) {
+        status.metadata_mut().insert(ERROR_CODE_METADATA, value);
+    }
+    status
+}
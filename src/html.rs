@@ -0,0 +1,90 @@
+//! HTML error pages of the `Html` derive.
+//!
+//! By default, the page is rendered with [`default_template`].
+//! A custom template is a function taking an [`ErrorPage`] set with `#[html(template = path::to::function)]`:
+//!
+//! ```
+//! use actix_web_error::html::ErrorPage;
+//!
+//! fn error_page(page: &ErrorPage) -> String {
+//!     format!("<main><h1>{}</h1><p>{}</p></main>", page.title, page.message)
+//! }
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Html)]
+//! #[error("The page <{0}> doesn't exist")]
+//! #[status(NOT_FOUND)]
+//! #[html(template = error_page)]
+//! struct PageNotFound(String);
+//! ```
+//!
+//! On enums, a variant's template takes precedence over the one of the enum.
+
+use actix_web::http::StatusCode;
+use std::fmt::Write;
+
+/// Contents of an error page. All texts are HTML-escaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPage {
+    pub status: StatusCode,
+    /// The status with its reason phrase, e.g. `404 Not Found`.
+    pub title: String,
+    /// The `Display` text of the error.
+    pub message: String,
+    pub code: Option<String>,
+}
+
+impl ErrorPage {
+    #[must_use]
+    pub fn new(status: StatusCode, message: &str, code: Option<&str>) -> Self {
+        let title = match status.canonical_reason() {
+            Some(reason) => format!("{} {}", status.as_u16(), reason),
+            None => status.as_u16().to_string(),
+        };
+        Self {
+            status,
+            title: escape(&title),
+            message: escape(message),
+            code: code.map(escape),
+        }
+    }
+}
+
+/// The built-in template.
+#[must_use]
+pub fn default_template(page: &ErrorPage) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n\
+         </head>\n\
+         <body>\n\
+         <h1>{title}</h1>\n\
+         <p>{message}</p>\n",
+        title = page.title,
+        message = page.message,
+    );
+    if let Some(code) = &page.code {
+        let _ = writeln!(html, "<p><code>{code}</code></p>");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Escapes `text` for use in HTML text and attribute values.
+#[must_use]
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,13 @@
+use http::{Response, StatusCode};
+
+/// Conversion of an error into an [`http::Response`] without actix-web.
+///
+/// Implemented by [`HttpJson`](crate::HttpJson) and [`HttpText`](crate::HttpText),
+/// which also implement `From<&E>` for `http::Response<Vec<u8>>`.
+pub trait IntoHttpResponse {
+    /// Status code of the response.
+    fn status_code(&self) -> StatusCode;
+
+    /// Creates the response with status, `Content-Type` and the serialized body.
+    fn http_response(&self) -> Response<Vec<u8>>;
+}
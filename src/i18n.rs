@@ -0,0 +1,277 @@
+//! Localized error messages.
+//!
+//! Errors mark translatable messages with `#[message(key = "..")]`.
+//! The [`Localize`] middleware picks the locale from the request's `Accept-Language` header
+//! and re-renders the message of such errors with a [`Translator`].
+//! The fields of the error are passed as arguments to the translation
+//! (named after the field, `0`, `1`, .. for tuple fields), so they have to implement [`Display`](std::fmt::Display).
+//!
+//! ```
+//! use actix_web::App;
+//! use actix_web_error::i18n::{Localize, MapTranslator};
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[status(NOT_FOUND)]
+//! enum ItemError {
+//!     #[error("Item {id} not found")]
+//!     #[message(key = "errors.item_not_found")]
+//!     NotFound { id: u32 },
+//! }
+//!
+//! let translator = MapTranslator::new()
+//!     .with("de", "errors.item_not_found", "Eintrag {id} nicht gefunden")
+//!     .with("fr", "errors.item_not_found", "Élément {id} introuvable");
+//! let app = App::new().wrap(Localize::new(translator));
+//! ```
+
+use crate::format::ResponseFormat;
+use actix_web::{
+    body::{BoxBody, EitherBody, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{AcceptLanguage, Header, HeaderValue, Preference, Quality, CONTENT_LANGUAGE},
+    Error, HttpResponse,
+};
+use futures_util::future::{ready, FutureExt, LocalBoxFuture, Ready};
+use std::{collections::HashMap, rc::Rc, sync::Arc};
+
+/// A source of translated messages.
+pub trait Translator {
+    /// Translates the message `key` into `locale` (a language tag, e.g. `de-AT` or `de`).
+    ///
+    /// Returns `None` if there's no translation, in which case the next preferred locale is tried.
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String>;
+}
+
+impl<T: Translator + ?Sized> Translator for Arc<T> {
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String> {
+        (**self).translate(locale, key, args)
+    }
+}
+
+impl<T: Translator + ?Sized> Translator for Box<T> {
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String> {
+        (**self).translate(locale, key, args)
+    }
+}
+
+/// A [`Translator`] with templates stored in a map.
+///
+/// Templates reference arguments with `{name}`, a literal `{` is written as `{{`.
+#[derive(Debug, Clone, Default)]
+pub struct MapTranslator {
+    templates: HashMap<String, HashMap<String, String>>,
+}
+
+impl MapTranslator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the template for `key` in `locale`.
+    #[must_use]
+    pub fn with(
+        mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.insert(locale, key, template);
+        self
+    }
+
+    /// Adds the template for `key` in `locale`, replacing the previous one.
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) {
+        self.templates
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), template.into());
+    }
+}
+
+impl Translator for MapTranslator {
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let template = self.templates.get(locale)?.get(key)?;
+        Some(render_template(template, args))
+    }
+}
+
+/// Replaces `{name}` with the argument `name`. Unknown arguments are kept as they are.
+fn render_template(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            out.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let arg = rest[1..].find('}').and_then(|end| {
+            let name = &rest[1..=end];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (value, end + 2))
+        });
+        if let Some((value, len)) = arg {
+            out.push_str(value);
+            rest = &rest[len..];
+        } else {
+            out.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Translation key and arguments of an error response, attached by the derives.
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct LocalizedMessage {
+    pub key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+    pub code: Option<&'static str>,
+    pub format: ResponseFormat,
+}
+
+impl LocalizedMessage {
+    pub fn attach(self, res: &mut HttpResponse) {
+        res.extensions_mut().insert(self);
+    }
+}
+
+/// Middleware translating the messages of errors with a `#[message(key = "..")]`.
+///
+/// The locales from `Accept-Language` are tried in order of preference,
+/// each followed by its primary language (`de` for `de-AT`), then the default locale.
+/// If none of them has a translation, the response is left as it is.
+/// The chosen locale is set in the `Content-Language` header.
+pub struct Localize<T> {
+    translator: Rc<T>,
+    default_locale: Option<String>,
+}
+
+impl<T: Translator> Localize<T> {
+    pub fn new(translator: T) -> Self {
+        Self {
+            translator: Rc::new(translator),
+            default_locale: None,
+        }
+    }
+
+    /// Sets the locale used if none of the requested ones has a translation.
+    #[must_use]
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = Some(locale.into());
+        self
+    }
+}
+
+impl<S, B, T> Transform<S, ServiceRequest> for Localize<T>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody,
+    T: Translator + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = LocalizeMiddleware<S, T>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(LocalizeMiddleware {
+            service,
+            translator: Rc::clone(&self.translator),
+            default_locale: self.default_locale.clone(),
+        }))
+    }
+}
+
+#[doc(hidden)]
+pub struct LocalizeMiddleware<S, T> {
+    service: S,
+    translator: Rc<T>,
+    default_locale: Option<String>,
+}
+
+impl<S, B, T> Service<ServiceRequest> for LocalizeMiddleware<S, T>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody,
+    T: Translator + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let mut locales = requested_locales(&req);
+        locales.extend(self.default_locale.clone());
+        let translator = Rc::clone(&self.translator);
+        let fut = self.service.call(req);
+
+        async move {
+            let res = fut.await?;
+            let translated = res
+                .response()
+                .extensions()
+                .get::<LocalizedMessage>()
+                .and_then(|message| {
+                    locales.into_iter().find_map(|locale| {
+                        let translated =
+                            translator.translate(&locale, message.key, &message.args)?;
+                        let body = message
+                            .format
+                            .render(res.status(), &translated, message.code);
+                        Some((locale, body))
+                    })
+                });
+
+            Ok(match translated {
+                Some((locale, body)) => res.map_body(|head, _| {
+                    if let Ok(locale) = HeaderValue::from_str(&locale) {
+                        head.headers_mut().insert(CONTENT_LANGUAGE, locale);
+                    }
+                    EitherBody::right(BoxBody::new(body))
+                }),
+                None => res.map_into_left_body(),
+            })
+        }
+        .boxed_local()
+    }
+}
+
+/// Locales from `Accept-Language` by preference, each followed by its primary language.
+fn requested_locales(req: &ServiceRequest) -> Vec<String> {
+    let Ok(AcceptLanguage(mut languages)) = AcceptLanguage::parse(req) else {
+        return Vec::new();
+    };
+    // stable, so equal preferences keep their order
+    languages.sort_by_key(|language| std::cmp::Reverse(language.quality));
+
+    let mut locales = Vec::new();
+    for language in languages {
+        let Preference::Specific(tag) = language.item else {
+            continue;
+        };
+        if language.quality == Quality::ZERO {
+            continue;
+        }
+        for locale in [tag.as_str(), tag.primary_language()] {
+            if !locales.iter().any(|l| l == locale) {
+                locales.push(locale.to_owned());
+            }
+        }
+    }
+    locales
+}
@@ -0,0 +1,109 @@
+//! [JSON:API](https://jsonapi.org/format/#errors) error objects of the `JsonApi` derive.
+//!
+//! The `status` is the resolved status as a string, `title` its reason phrase,
+//! `detail` the [`Display`](std::fmt::Display) representation and `code` the `#[error_code(..)]`.
+//! The `source` is set with `#[json_api(pointer = "..")]` or `#[json_api(parameter = "..")]` on a struct or variant,
+//! or taken from a field with `#[json_api(pointer)]` or `#[json_api(parameter)]`:
+//!
+//! ```
+//! #[derive(Debug, thiserror::Error, actix_web_error::JsonApi)]
+//! #[status(422)]
+//! enum ArticleError {
+//!     #[error("The title must not be empty")]
+//!     #[error_code("empty_title")]
+//!     #[json_api(pointer = "/data/attributes/title")]
+//!     EmptyTitle,
+//!     #[error("Unknown sort field")]
+//!     #[status(400)]
+//!     UnknownSort(#[json_api(parameter)] String),
+//! }
+//! ```
+
+use actix_web::http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// The media type of JSON:API documents.
+pub const CONTENT_TYPE: &str = "application/vnd.api+json";
+
+/// A JSON:API document with errors.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct JsonApiDocument {
+    #[cfg_attr(feature = "utoipa", schema(inline))]
+    pub errors: Vec<JsonApiError>,
+}
+
+/// A JSON:API error object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct JsonApiError {
+    /// The HTTP status code as a string.
+    pub status: String,
+    /// The error code if the error has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The reason phrase of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The [`Display`](std::fmt::Display) representation of the error.
+    pub detail: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(inline))]
+    pub source: Option<JsonApiSource>,
+}
+
+/// The part of the request that caused the error.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct JsonApiSource {
+    /// A JSON pointer into the request document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+    /// The query parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+}
+
+impl JsonApiError {
+    #[must_use]
+    pub fn new(
+        status: StatusCode,
+        detail: String,
+        code: Option<&str>,
+        source: Option<JsonApiSource>,
+    ) -> Self {
+        Self {
+            status: status.as_u16().to_string(),
+            code: code.map(ToOwned::to_owned),
+            title: status.canonical_reason().map(ToOwned::to_owned),
+            detail,
+            source,
+        }
+    }
+}
+
+impl JsonApiSource {
+    #[must_use]
+    pub fn pointer(pointer: impl Into<String>) -> Self {
+        Self {
+            pointer: Some(pointer.into()),
+            parameter: None,
+        }
+    }
+
+    #[must_use]
+    pub fn parameter(parameter: impl Into<String>) -> Self {
+        Self {
+            pointer: None,
+            parameter: Some(parameter.into()),
+        }
+    }
+}
+
+impl From<JsonApiError> for JsonApiDocument {
+    fn from(error: JsonApiError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
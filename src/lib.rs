@@ -1,12 +1,23 @@
 //! Error responses for actix-web made easy.
-//! <!-- We don't depend on `actix_web` -->
-//! This crate will make it easy implementing [`actix_web::ResponseError`](https://docs.rs/actix-web/latest/actix_web/trait.ResponseError.html) for errors.
+//! This crate will make it easy implementing [`actix_web::ResponseError`] for errors.
 //! It's best used in combination with [thiserror](https://docs.rs/thiserror).
 //!
 //! # Error Responses
 //!
-//! * [`Json`] will respond with JSON in the form of `{ "error": <`[`Display`](std::fmt::Display)` representation> }` (`application/json`).
+//! * [`Json`] will respond with JSON in the form of `{ "message": <`[`Display`](std::fmt::Display)` representation>, "code": <error code> }` (`application/json`).
+//!   The `code` is only present if an `#[error_code(..)]` is specified.
 //! * [`Text`] will respond with the [`Display`](std::fmt::Display) representation of the error (`text/plain`).
+//! * [`Html`] will respond with an HTML page showing the status and the escaped [`Display`](std::fmt::Display) representation (`text/html`).
+//!   The page can be customized with `#[html(template = ..)]`, see [`html`].
+//! * [`Xml`] will respond with `<error><message>..</message><code>..</code></error>` (`application/xml`),
+//!   the XML equivalent of [`Json`].
+//! * [`JsonApi`] will respond with a [JSON:API](https://jsonapi.org/format/#errors) document (`application/vnd.api+json`),
+//!   see [`json_api`].
+//! * [`GoogleJson`] will respond with the [Google API error model](https://cloud.google.com/apis/design/errors)
+//!   `{ "error": { "code": 404, "status": "NOT_FOUND", "message": .., "details": [..] } }` (`application/json`),
+//!   see [`google`].
+//! * `MsgPack` (feature `msgpack`) and `Cbor` (feature `cbor`) will respond with the structure of [`Json`]
+//!   serialized as [MessagePack](https://msgpack.org) (`application/msgpack`) or CBOR (`application/cbor`).
 //!
 //! # Example
 //!
@@ -30,42 +41,233 @@
 //! # fn main() {}
 //! ```
 //!
+//! # Wrapping Errors
+//!
+//! `#[status(transparent)]` takes the status from the only field, `#[error_code(transparent)]` its [`ErrorCode`],
+//! and `#[response(transparent)]` forwards the whole response (status, error code and body):
+//!
+//! ```
+//! #[derive(Debug, thiserror::Error, actix_web_error::Text)]
+//! #[error("Out of stock")]
+//! #[status(CONFLICT)]
+//! struct StockError;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! enum CheckoutError {
+//!     #[error(transparent)]
+//!     #[response(transparent)]
+//!     Stock(#[from] StockError),
+//! }
+//! ```
+//!
+//! # Multiple Errors
+//!
+//! [`ErrorList`] collects errors of a derived type (e.g. from form validation)
+//! and responds with a JSON array of their bodies. The status is chosen by a [`StatusStrategy`].
+//!
+//! # `validator`
+//!
+//! With the `validator` feature, [`validation::ValidationFailed`] turns `validator::ValidationErrors`
+//! into `422` responses listing the field-level errors with JSON pointers.
+//!
+//! # Decoding Responses
+//!
+//! Types deriving [`Json`] with `#[decode]` implement `TryFrom<`[`ErrorBody`]`>` and get a `from_response(status, body)` function,
+//! which decode unit variants from a response (matched by their error code or message).
+//!
+//! # `utoipa`
+//!
+//! With the `utoipa` feature, the derives implement `utoipa::IntoResponses`, grouping variants by their status.
+//! The `#[error]` format strings and error codes are used as examples, and [`ErrorBody`] implements `utoipa::ToSchema`.
+//!
+//! # `schemars`
+//!
+//! With the `schemars` feature, the derives implement `schemars::JsonSchema` describing their response body.
+//! For [`Json`], the `code` property is an `enum` of the error codes declared on the type.
+//!
+//! # axum
+//!
+//! With the `axum` feature, `AxumJson` and `AxumText` implement `axum::response::IntoResponse`
+//! from the same attributes, so an error type can serve both frameworks.
+//! A `#[status(transparent)]` field has to implement `IntoResponse` for these.
+//!
+//! # `tonic`
+//!
+//! With the `tonic` feature, the derives of types marked with `#[grpc]` implement `From<E> for tonic::Status`,
+//! so the same errors can be returned from gRPC services, see [`grpc`].
+//!
+//! # `async-graphql`
+//!
+//! With the `async-graphql` feature, the derives implement `async_graphql::ErrorExtensions`.
+//! The extensions contain the `status`, the `code` (if any) and the fields marked with `#[detail]`,
+//! which have to implement [`Serialize`](serde::Serialize):
+//!
+//! ```
+//! # #[cfg(feature = "async-graphql")]
+//! # {
+//! use async_graphql::ErrorExtensions;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[status(NOT_FOUND)]
+//! enum UserError {
+//!     #[error("User {id} not found")]
+//!     #[error_code("user_not_found")]
+//!     NotFound {
+//!         #[detail]
+//!         id: u32,
+//!     },
+//! }
+//!
+//! // { "status": 404, "code": "user_not_found", "id": 3 }
+//! let error = UserError::NotFound { id: 3 }.extend();
+//! # }
+//! ```
+//!
+//! # `ws`
+//!
+//! With the `ws` feature, the derives implement [`ws::WsClose`],
+//! turning errors into WebSocket close frames with the code from `#[ws_close(..)]`.
+//!
+//! # `http`
+//!
+//! With the `http` feature, `HttpJson` and `HttpText` implement `IntoHttpResponse`
+//! and `From<&E>` for `http::Response<Vec<u8>>`, independent of any framework.
+//!
+//! # Library Errors
+//!
+//! The `wrappers` assign statuses to errors of `std::io` (feature `io`), `serde_json` (feature `json`),
+//! timeouts (feature `timeout`) and `sqlx` (feature `sqlx`),
+//! so variants wrapping them can use `#[status(transparent)]`.
+//!
+//! # Source Chains
+//!
+//! With `#[status(source)]`, the status is taken from the first error in the `source()` chain
+//! of a field that's registered in the [`source`] module, so boxed errors and `anyhow::Error`
+//! can respond with the status of the error they wrap.
+//!
+//! # `anyhow` / `eyre`
+//!
+//! With the `anyhow` or `eyre` feature, handlers can return [`report::AnyhowResponse`] or [`report::EyreResponse`],
+//! which respond with the first registered error in the chain and a masked `500 Internal Server Error` otherwise.
+//!
+//! # Fallback Responses
+//!
+//! Responses generated by actix-web itself (unmatched routes, wrong methods) don't have a body.
+//! The [`fallback`] module provides a default service and middleware rendering them as JSON.
+//!
+//! # Panics in Handlers
+//!
+//! With the `panic` feature, `panic::CatchPanic` catches panics in handlers and responds with an error type of your choice.
+//!
+//! # Server-Sent Events
+//!
+//! [`sse::error_frame`] renders an error as an `event: error` frame for streams which already started.
+//!
+//! # Localized Messages
+//!
+//! Messages with a `#[message(key = "..")]` are translated by the [`i18n::Localize`] middleware
+//! into the locale requested in `Accept-Language`.
+//!
 #![warn(clippy::cargo)]
 #![warn(clippy::pedantic)]
+#![allow(clippy::multiple_crate_versions)]
 
 pub use actix_web_error_derive::*;
 
+mod body;
+mod error_code;
+pub mod fallback;
+mod format;
+pub mod google;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+pub mod grpc;
+pub mod html;
+#[cfg(feature = "http")]
+mod http_response;
+pub mod i18n;
+pub mod json_api;
+mod list;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "panic")]
+pub mod panic;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+pub mod report;
+#[cfg(feature = "schemars")]
+mod schema;
+pub mod source;
+pub mod sse;
+#[cfg(feature = "validator")]
+pub mod validation;
+#[cfg(any(
+    feature = "io",
+    feature = "json",
+    feature = "timeout",
+    feature = "sqlx"
+))]
+pub mod wrappers;
+#[cfg(feature = "ws")]
+pub mod ws;
+
+pub use body::ErrorBody;
+pub use error_code::ErrorCode;
+#[cfg(feature = "http")]
+pub use http_response::IntoHttpResponse;
+pub use list::{ErrorList, StatusStrategy};
+
 #[doc(hidden)]
 pub mod __private {
     use serde::{ser::SerializeStruct, Serialize, Serializer};
     use std::fmt::Display;
 
-    pub struct JsonErrorSerialize<'a, T> {
+    pub use crate::format::ResponseFormat;
+    #[cfg(feature = "async-graphql")]
+    pub use crate::graphql::{graphql_error, set_detail as set_graphql_detail};
+    #[cfg(feature = "tonic")]
+    pub use crate::grpc::tonic_status;
+    pub use crate::i18n::LocalizedMessage;
+    #[cfg(feature = "utoipa")]
+    pub use crate::openapi::{
+        forward_responses as utoipa_forward_responses, responses as utoipa_responses, ResponseEntry,
+    };
+    #[cfg(feature = "schemars")]
+    pub use crate::schema::{
+        forwarding_schema as schemars_forwarding_schema, schema as schemars_schema,
+    };
+    pub use crate::source::AsDynError;
+    #[cfg(feature = "async-graphql")]
+    pub use async_graphql;
+    #[cfg(feature = "http")]
+    pub use http;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    pub use serde;
+    pub use serde_json;
+    #[cfg(feature = "tonic")]
+    pub use tonic;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+
+    pub struct JsonErrorSerialize<'a, T: ?Sized> {
         pub message: &'a T,
         pub code: Option<&'a str>,
     }
 
-    impl<'a, T> Serialize for JsonErrorSerialize<'a, T>
+    impl<T> Serialize for JsonErrorSerialize<'_, T>
     where
-        T: Display,
+        T: Display + ?Sized,
     {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            match self.code {
-                Some(error_code) => {
-                    let mut ser = serializer.serialize_struct("_", 2)?;
-                    ser.serialize_field("message", &self.message.to_string())?;
-                    ser.serialize_field("code", &error_code.to_string())?;
-                    ser.end()
-                }
-                None => {
-                    let mut ser = serializer.serialize_struct("_", 2)?;
-                    ser.serialize_field("message", &self.message.to_string())?;
-                    ser.end()
-                }
+            let mut ser = serializer.serialize_struct("_", 1 + usize::from(self.code.is_some()))?;
+            ser.serialize_field("message", &self.message.to_string())?;
+            if let Some(error_code) = self.code {
+                ser.serialize_field("code", error_code)?;
             }
+            ser.end()
         }
     }
 }
@@ -0,0 +1,194 @@
+//! Responses with multiple errors.
+
+use crate::{__private::JsonErrorSerialize, ErrorCode};
+use actix_web::{
+    body::BoxBody, http::header::ContentType, http::StatusCode, HttpResponse, HttpResponseBuilder,
+    ResponseError,
+};
+use std::fmt;
+
+/// How the status of an [`ErrorList`] is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusStrategy {
+    /// The status of the first error.
+    First,
+    /// The highest status of all errors, so server errors take precedence over client errors.
+    #[default]
+    MostSevere,
+    /// A fixed status, e.g. `400` or `422`.
+    Fixed(StatusCode),
+}
+
+/// Multiple errors responding with a JSON array of their bodies.
+///
+/// Each body has the structure of [`Json`](crate::Json): `[{ "message": .., "code": .. }, ..]`.
+/// The status is chosen by the [`StatusStrategy`], an empty list responds with `500`.
+///
+/// ```
+/// use actix_web::http::StatusCode;
+/// use actix_web_error::{ErrorList, StatusStrategy};
+///
+/// #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+/// #[status(BAD_REQUEST)]
+/// enum FieldError {
+///     #[error("{0} is required")]
+///     #[error_code("required")]
+///     Required(&'static str),
+///     #[error("{0} is too long")]
+///     TooLong(&'static str),
+/// }
+///
+/// fn validate(name: &str, email: &str) -> Result<(), ErrorList<FieldError>> {
+///     let mut errors = ErrorList::new().status(StatusStrategy::Fixed(StatusCode::UNPROCESSABLE_ENTITY));
+///     if name.is_empty() {
+///         errors.push(FieldError::Required("name"));
+///     }
+///     if email.len() > 254 {
+///         errors.push(FieldError::TooLong("email"));
+///     }
+///     errors.into_result()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ErrorList<E> {
+    errors: Vec<E>,
+    strategy: StatusStrategy,
+}
+
+impl<E> ErrorList<E> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            errors: Vec::new(),
+            strategy: StatusStrategy::default(),
+        }
+    }
+
+    /// Sets how the status of the response is chosen.
+    #[must_use]
+    pub fn status(mut self, strategy: StatusStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn push(&mut self, error: E) {
+        self.errors.push(error);
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, E> {
+        self.errors.iter()
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// `Ok` if there are no errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns the list if it's not empty.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<E> Default for ErrorList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> From<Vec<E>> for ErrorList<E> {
+    fn from(errors: Vec<E>) -> Self {
+        Self {
+            errors,
+            strategy: StatusStrategy::default(),
+        }
+    }
+}
+
+impl<E> FromIterator<E> for ErrorList<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Vec::from_iter(iter).into()
+    }
+}
+
+impl<E> Extend<E> for ErrorList<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for ErrorList<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a ErrorList<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+/// The messages of all errors, separated by `; `.
+impl<E: fmt::Display> fmt::Display for ErrorList<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            error.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ErrorList<E> {}
+
+impl<E: ResponseError + ErrorCode> ResponseError for ErrorList<E> {
+    fn status_code(&self) -> StatusCode {
+        let status = match self.strategy {
+            StatusStrategy::Fixed(status) => return status,
+            StatusStrategy::First => self.errors.first().map(ResponseError::status_code),
+            StatusStrategy::MostSevere => self.errors.iter().map(ResponseError::status_code).max(),
+        };
+        status.unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let bodies: Vec<_> = self
+            .errors
+            .iter()
+            .map(|error| JsonErrorSerialize {
+                message: error,
+                code: error.error_code(),
+            })
+            .collect();
+        HttpResponseBuilder::new(self.status_code())
+            .content_type(ContentType::json())
+            .body(crate::format::json_body(&bodies))
+    }
+}
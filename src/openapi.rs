@@ -0,0 +1,188 @@
+use crate::{
+    __private::JsonErrorSerialize, format::ResponseFormat, google::GoogleErrorBody, grpc::GrpcCode,
+    json_api::JsonApiDocument, ErrorBody,
+};
+use actix_web::http::StatusCode;
+use std::collections::{btree_map::Entry, BTreeMap};
+use utoipa::{
+    openapi::{
+        example::ExampleBuilder,
+        response::{Response, ResponseBuilder},
+        schema::Schema,
+        ContentBuilder, RefOr,
+    },
+    PartialSchema,
+};
+
+impl ResponseFormat {
+    fn schema(self) -> RefOr<Schema> {
+        match self {
+            ResponseFormat::Text | ResponseFormat::Html(_) => String::schema(),
+            ResponseFormat::JsonApi => JsonApiDocument::schema(),
+            ResponseFormat::Google(_) => GoogleErrorBody::schema(),
+            // XML and binary bodies have the same structure as JSON
+            ResponseFormat::Json | ResponseFormat::Xml => ErrorBody::schema(),
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => ErrorBody::schema(),
+            #[cfg(feature = "cbor")]
+            ResponseFormat::Cbor => ErrorBody::schema(),
+        }
+    }
+}
+
+/// A struct or variant of a derived error.
+#[derive(Debug)]
+pub struct ResponseEntry {
+    /// `None` for `#[status(transparent)]` and `#[status(source)]`.
+    pub status: Option<u16>,
+    pub name: &'static str,
+    /// The format string of `#[error("..")]` with placeholders replaced by their argument (`<id>`).
+    pub message: Option<&'static str>,
+    /// `<code>` for `#[error_code(transparent)]`.
+    pub code: Option<&'static str>,
+    /// The `#[grpc(code = ..)]` of the entry.
+    pub grpc_code: Option<GrpcCode>,
+}
+
+impl ResponseEntry {
+    /// The status of the entry, `500` for transparent ones.
+    fn status_code(&self) -> StatusCode {
+        self.status
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn example(&self, format: ResponseFormat) -> Option<serde_json::Value> {
+        let message = self.message?;
+        Some(match format {
+            ResponseFormat::Text => message.into(),
+            ResponseFormat::Html(_) | ResponseFormat::Xml => {
+                String::from_utf8(format.render(self.status_code(), message, self.code))
+                    .ok()?
+                    .into()
+            }
+            ResponseFormat::JsonApi => {
+                serde_json::from_slice(&format.render(self.status_code(), message, self.code))
+                    .ok()?
+            }
+            ResponseFormat::Google(grpc_code) => serde_json::from_slice(
+                &ResponseFormat::Google(self.grpc_code.or(grpc_code)).render(
+                    self.status_code(),
+                    message,
+                    self.code,
+                ),
+            )
+            .ok()?,
+            ResponseFormat::Json => self.json_example(message)?,
+            // binary bodies are shown with the same structure as JSON
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => self.json_example(message)?,
+            #[cfg(feature = "cbor")]
+            ResponseFormat::Cbor => self.json_example(message)?,
+        })
+    }
+
+    fn json_example(&self, message: &str) -> Option<serde_json::Value> {
+        serde_json::to_value(JsonErrorSerialize {
+            message,
+            code: self.code,
+        })
+        .ok()
+    }
+}
+
+/// Groups the `entries` by status, transparent ones are grouped as `default`.
+#[must_use]
+pub fn responses(
+    format: ResponseFormat,
+    entries: &[ResponseEntry],
+) -> BTreeMap<String, RefOr<Response>> {
+    let mut grouped: BTreeMap<Option<u16>, Vec<&ResponseEntry>> = BTreeMap::new();
+    for entry in entries {
+        grouped.entry(entry.status).or_default().push(entry);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(status, entries)| {
+            let description = status
+                .and_then(|status| StatusCode::from_u16(status).ok())
+                .and_then(|status| status.canonical_reason())
+                .unwrap_or("Error");
+            let examples = entries.iter().filter_map(|entry| {
+                let example = ExampleBuilder::new().value(entry.example(format));
+                entry.message.map(|_| (entry.name, example))
+            });
+            let content = ContentBuilder::new()
+                .schema(Some(format.schema()))
+                .examples_from_iter(examples)
+                .build();
+            let response = ResponseBuilder::new()
+                .description(description)
+                .content(format.content_type(), content)
+                .build();
+            (
+                status.map_or_else(|| "default".to_owned(), |status| status.to_string()),
+                response.into(),
+            )
+        })
+        .collect()
+}
+
+/// Plain text responses with `statuses` and no examples, for errors not derived by this crate.
+#[cfg(any(
+    feature = "io",
+    feature = "json",
+    feature = "timeout",
+    feature = "sqlx"
+))]
+pub(crate) fn text_responses(
+    name: &'static str,
+    statuses: &[u16],
+) -> BTreeMap<String, RefOr<Response>> {
+    let entries: Vec<_> = statuses
+        .iter()
+        .map(|&status| ResponseEntry {
+            status: Some(status),
+            name,
+            message: None,
+            code: None,
+            grpc_code: None,
+        })
+        .collect();
+    responses(ResponseFormat::Text, &entries)
+}
+
+/// Adds the `forwarded` responses of a `#[response(transparent)]` struct or variant to `responses`.
+///
+/// Responses with the same status are merged, keeping the examples already in `responses`.
+pub fn forward_responses(
+    responses: &mut BTreeMap<String, RefOr<Response>>,
+    forwarded: BTreeMap<String, RefOr<Response>>,
+) {
+    for (status, forwarded) in forwarded {
+        match (responses.entry(status), forwarded) {
+            (Entry::Vacant(entry), forwarded) => {
+                entry.insert(forwarded);
+            }
+            (Entry::Occupied(mut entry), RefOr::T(forwarded)) => {
+                let RefOr::T(response) = entry.get_mut() else {
+                    continue;
+                };
+                for (content_type, forwarded) in forwarded.content {
+                    match response.content.get_mut(&content_type) {
+                        Some(content) => {
+                            for (name, example) in forwarded.examples {
+                                content.examples.entry(name).or_insert(example);
+                            }
+                        }
+                        None => {
+                            response.content.insert(content_type, forwarded);
+                        }
+                    }
+                }
+            }
+            (Entry::Occupied(_), RefOr::Ref(_)) => {}
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Middleware catching panics in handlers.
+//!
+//! Without it, a panicking handler results in a dropped connection.
+//! [`CatchPanic`] responds with an error created from the panic instead.
+//! The response is returned as an [`Error`] from the middleware, so outer middleware sees a failed request.
+//!
+//! ```
+//! use actix_web::App;
+//! use actix_web_error::panic::{CatchPanic, Panic};
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[error("Internal Server Error (correlation id: {0})")]
+//! #[status(500)]
+//! struct InternalError(String);
+//!
+//! let app = App::new().wrap(CatchPanic::new(|panic: Panic| InternalError(panic.correlation_id)));
+//! ```
+
+use actix_web::{
+    body::MessageBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::InternalError,
+    http::header::{HeaderName, HeaderValue},
+    Error, ResponseError,
+};
+use futures_util::future::{ready, FutureExt, LocalBoxFuture, Ready};
+use std::{any::Any, panic::AssertUnwindSafe, rc::Rc};
+
+/// Information about a caught panic, passed to the error constructor of [`CatchPanic`].
+#[derive(Debug, Clone)]
+pub struct Panic {
+    /// Correlation ID of the request.
+    ///
+    /// Taken from the request's correlation header if present, otherwise a random UUID.
+    pub correlation_id: String,
+    /// The panic payload if it's a string, `Box<dyn Any>` otherwise.
+    pub message: String,
+}
+
+/// Middleware responding with an error created by `F` if a handler panics.
+///
+/// The panic is logged with the [`log`] crate (level `error`),
+/// and the correlation ID is set as a header on the response.
+pub struct CatchPanic<F> {
+    make_error: Rc<F>,
+    header: HeaderName,
+}
+
+impl<F, E> CatchPanic<F>
+where
+    F: Fn(Panic) -> E,
+    E: ResponseError,
+{
+    /// Creates the middleware with the function creating the error for a caught panic.
+    pub fn new(make_error: F) -> Self {
+        Self {
+            make_error: Rc::new(make_error),
+            header: HeaderName::from_static("x-request-id"),
+        }
+    }
+
+    /// Sets the header used to read and write the correlation ID (default: `x-request-id`).
+    #[must_use]
+    pub fn correlation_header(mut self, header: HeaderName) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+impl<S, B, F, E> Transform<S, ServiceRequest> for CatchPanic<F>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody,
+    F: Fn(Panic) -> E + 'static,
+    E: ResponseError + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = CatchPanicMiddleware<S, F>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CatchPanicMiddleware {
+            service: Rc::new(service),
+            make_error: Rc::clone(&self.make_error),
+            header: self.header.clone(),
+        }))
+    }
+}
+
+#[doc(hidden)]
+pub struct CatchPanicMiddleware<S, F> {
+    service: Rc<S>,
+    make_error: Rc<F>,
+    header: HeaderName,
+}
+
+impl<S, B, F, E> Service<ServiceRequest> for CatchPanicMiddleware<S, F>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody,
+    F: Fn(Panic) -> E + 'static,
+    E: ResponseError + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let make_error = Rc::clone(&self.make_error);
+        let header = self.header.clone();
+        let correlation_id = req
+            .headers()
+            .get(&header)
+            .and_then(|value| value.to_str().ok())
+            .map_or_else(|| uuid::Uuid::new_v4().to_string(), ToOwned::to_owned);
+
+        async move {
+            let payload = match AssertUnwindSafe(async move { service.call(req).await })
+                .catch_unwind()
+                .await
+            {
+                Ok(res) => return res,
+                Err(payload) => payload,
+            };
+
+            let message = payload_message(payload.as_ref());
+            log::error!("handler panicked (correlation id: {correlation_id}): {message}");
+
+            let error = make_error(Panic {
+                correlation_id: correlation_id.clone(),
+                message,
+            });
+            let mut res = error.error_response();
+            if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+                res.headers_mut().insert(header, value);
+            }
+            Err(InternalError::from_response(error, res).into())
+        }
+        .boxed_local()
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
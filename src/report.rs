@@ -0,0 +1,106 @@
+//! Responses for `anyhow::Error` (feature `anyhow`) and `eyre::Report` (feature `eyre`).
+//!
+//! Handlers returning `anyhow::Result` respond with `500 Internal Server Error` and the
+//! message of the error. Returning [`AnyhowResponse`] instead searches the `source()` chain
+//! for errors registered in the [`source`](crate::source) module:
+//!
+//! * A registered [`ResponseError`] renders its own response, in the format of its derive.
+//! * Other known errors (e.g. `std::io::Error` with the `io` feature) respond with their status and its canonical reason.
+//! * Otherwise, the error is logged and the response is a masked `500 Internal Server Error`.
+//!
+//! ```
+//! # #[cfg(feature = "anyhow")]
+//! # {
+//! use actix_web::{http::StatusCode, ResponseError};
+//! use actix_web_error::report::AnyhowResponse;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[error("User not found")]
+//! #[status(NOT_FOUND)]
+//! struct UserNotFound;
+//!
+//! actix_web_error::source::register::<UserNotFound>();
+//!
+//! fn load_user() -> Result<(), AnyhowResponse> {
+//!     Err(anyhow::Error::new(UserNotFound).context("loading the profile"))?;
+//!     Ok(())
+//! }
+//!
+//! assert_eq!(load_user().unwrap_err().status_code(), StatusCode::NOT_FOUND);
+//! # }
+//! ```
+
+use crate::source::{self, Found};
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use std::{error::Error, fmt};
+
+/// Implements `From`, `Display` and `ResponseError` for a wrapper around an error report.
+macro_rules! report {
+    ($wrapper:ident($inner:ty)) => {
+        impl<E: Into<$inner>> From<E> for $wrapper {
+            fn from(error: E) -> Self {
+                Self(error.into())
+            }
+        }
+
+        impl fmt::Display for $wrapper {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ResponseError for $wrapper {
+            fn status_code(&self) -> StatusCode {
+                source::status_of(&*self.0).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+
+            fn error_response(&self) -> HttpResponse {
+                chain_response(&*self.0)
+            }
+        }
+    };
+}
+
+/// An `anyhow::Error` responding with the first registered error in its chain.
+#[cfg(feature = "anyhow")]
+#[derive(Debug)]
+pub struct AnyhowResponse(pub anyhow::Error);
+
+#[cfg(feature = "anyhow")]
+report!(AnyhowResponse(anyhow::Error));
+
+/// An `eyre::Report` responding with the first registered error in its chain.
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct EyreResponse(pub eyre::Report);
+
+#[cfg(feature = "eyre")]
+report!(EyreResponse(eyre::Report));
+
+fn chain_response(error: &(dyn Error + 'static)) -> HttpResponse {
+    match source::find(error) {
+        Some(Found::Response(error)) => error.error_response(),
+        found => {
+            let status = found.map_or(StatusCode::INTERNAL_SERVER_ERROR, |found| found.status());
+            if status.is_server_error() {
+                log::error!("{}", Chain(error));
+            }
+            HttpResponse::build(status).json(crate::fallback::reason_body(status))
+        }
+    }
+}
+
+/// Displays an error and its sources separated by `: `.
+struct Chain<'a>(&'a (dyn Error + 'static));
+
+impl fmt::Display for Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        let mut source = self.0.source();
+        while let Some(error) = source {
+            write!(f, ": {error}")?;
+            source = error.source();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{format::ResponseFormat, google, grpc::GrpcCode};
+use schemars::{json_schema, Schema};
+use serde_json::{json, Map, Value};
+
+/// JSON Schema of the body of a derived error with the error `codes` of its variants.
+///
+/// The `code` property is restricted to the declared codes, unless `any_code` is set by
+/// `#[error_code(transparent)]`, whose codes aren't known.
+#[must_use]
+pub fn schema(format: ResponseFormat, codes: &[Option<&'static str>], any_code: bool) -> Schema {
+    let mut declared = Vec::new();
+    for code in codes.iter().flatten() {
+        if !declared.contains(code) {
+            declared.push(*code);
+        }
+    }
+    let code = if any_code {
+        Some(json!({ "type": "string" }))
+    } else {
+        (!declared.is_empty()).then(|| json!({ "type": "string", "enum": declared }))
+    };
+
+    match format {
+        ResponseFormat::Text | ResponseFormat::Html(_) => json_schema!({ "type": "string" }),
+        ResponseFormat::JsonApi => {
+            let mut properties = Map::new();
+            properties.insert("status".to_owned(), json!({ "type": "string" }));
+            properties.extend(code.map(|code| ("code".to_owned(), code)));
+            properties.insert("title".to_owned(), json!({ "type": "string" }));
+            properties.insert("detail".to_owned(), json!({ "type": "string" }));
+            properties.insert(
+                "source".to_owned(),
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "pointer": { "type": "string" },
+                        "parameter": { "type": "string" },
+                    },
+                    "additionalProperties": false,
+                }),
+            );
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "errors": {
+                        "type": "array",
+                        "items": object(properties, &["status", "detail"]),
+                    },
+                },
+                "required": ["errors"],
+            })
+        }
+        ResponseFormat::Google(_) => {
+            let statuses: Vec<_> = GrpcCode::ALL.iter().map(|code| code.name()).collect();
+            let mut properties = Map::new();
+            properties.insert("code".to_owned(), json!({ "type": "integer" }));
+            properties.insert(
+                "status".to_owned(),
+                json!({ "type": "string", "enum": statuses }),
+            );
+            properties.insert("message".to_owned(), json!({ "type": "string" }));
+            let mut detail = Map::new();
+            detail.insert(
+                "@type".to_owned(),
+                json!({ "type": "string", "const": google::ERROR_INFO_TYPE }),
+            );
+            detail.insert(
+                "reason".to_owned(),
+                code.unwrap_or_else(|| json!({ "type": "string" })),
+            );
+            properties.insert(
+                "details".to_owned(),
+                json!({ "type": "array", "items": object(detail, &["@type", "reason"]) }),
+            );
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "error": object(properties, &["code", "status", "message"]),
+                },
+                "required": ["error"],
+            })
+        }
+        // XML and binary bodies have the same structure as JSON
+        ResponseFormat::Json | ResponseFormat::Xml => error_body(code),
+        #[cfg(feature = "msgpack")]
+        ResponseFormat::MsgPack => error_body(code),
+        #[cfg(feature = "cbor")]
+        ResponseFormat::Cbor => error_body(code),
+    }
+}
+
+/// JSON Schema of the body of a derived error with `#[response(transparent)]` variants,
+/// which is any of its `own` schema (`None` if all variants forward) and the `forwarded` ones.
+#[must_use]
+pub fn forwarding_schema(own: Option<Schema>, forwarded: Vec<Schema>) -> Schema {
+    let mut schemas: Vec<_> = own.into_iter().chain(forwarded).collect();
+    match schemas.pop() {
+        Some(schema) if schemas.is_empty() => schema,
+        last => {
+            schemas.extend(last);
+            json_schema!({ "anyOf": schemas })
+        }
+    }
+}
+
+/// `{ "message": .., "code": .. }`
+fn error_body(code: Option<Value>) -> Schema {
+    let mut properties = Map::new();
+    properties.insert("message".to_owned(), json!({ "type": "string" }));
+    properties.extend(code.map(|code| ("code".to_owned(), code)));
+    Schema::from(object(properties, &["message"]))
+}
+
+fn object(properties: Map<String, Value>, required: &[&str]) -> Map<String, Value> {
+    let mut object = Map::new();
+    object.insert("type".to_owned(), json!("object"));
+    object.insert("properties".to_owned(), properties.into());
+    object.insert("required".to_owned(), json!(required));
+    object.insert("additionalProperties".to_owned(), false.into());
+    object
+}
@@ -0,0 +1,219 @@
+//! Statuses from the [`source()`](Error::source) chain of an error.
+//!
+//! `#[status(source)]` walks the chain of a field (e.g. `Box<dyn Error>` or `anyhow::Error`)
+//! and responds with the status of the first error it knows.
+//! If there's none, the status of the enum is used, `500 Internal Server Error` otherwise.
+//! The field is the one with `#[source]` or `#[from]`, or the only field.
+//!
+//! ```
+//! use actix_web::{http::StatusCode, ResponseError};
+//! use actix_web_error::source;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[error("Not allowed")]
+//! #[status(FORBIDDEN)]
+//! struct Forbidden;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[error("Request failed")]
+//! #[status(source)]
+//! struct RequestError(#[source] Box<dyn std::error::Error + Send + Sync>);
+//!
+//! source::register::<Forbidden>();
+//!
+//! let error = RequestError(Box::new(Forbidden));
+//! assert_eq!(error.status_code(), StatusCode::FORBIDDEN);
+//! let error = RequestError("unknown".into());
+//! assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+//! ```
+//!
+//! Registered errors are checked before the built-in ones, which are the errors
+//! of the enabled `wrappers` (`std::io`, `serde_json`, timeouts and `sqlx`)
+//! and `validator`'s `ValidationErrors` (feature `validator`).
+
+use actix_web::{http::StatusCode, ResponseError};
+#[cfg(feature = "io")]
+use std::io;
+use std::{
+    any::TypeId,
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+type ResponseLookup =
+    for<'a> fn(&'a (dyn Error + 'static)) -> Option<&'a (dyn ResponseError + 'static)>;
+type StatusLookup = Arc<dyn Fn(&(dyn Error + 'static)) -> Option<StatusCode> + Send + Sync>;
+
+#[derive(Clone)]
+enum Lookup {
+    Response(ResponseLookup),
+    Status(StatusLookup),
+}
+
+/// A lookup of the registered error type.
+type Registration = (TypeId, Lookup);
+
+/// An error found in a `source()` chain.
+pub(crate) enum Found<'a> {
+    /// A registered [`ResponseError`].
+    Response(&'a (dyn ResponseError + 'static)),
+    /// An error registered with [`register_with`] or a built-in one.
+    Status(StatusCode),
+}
+
+impl Found<'_> {
+    pub(crate) fn status(&self) -> StatusCode {
+        match self {
+            Found::Response(error) => error.status_code(),
+            Found::Status(status) => *status,
+        }
+    }
+}
+
+/// The lookups are replaced on registration, so they can be called without holding the lock
+/// (lookups of `#[status(source)]` errors query the registry again).
+static REGISTRY: RwLock<Option<Arc<[Registration]>>> = RwLock::new(None);
+
+/// Registers `E`, responding with its [`ResponseError::status_code`].
+///
+/// Registering `E` again replaces its previous registration.
+pub fn register<E: ResponseError + Error + 'static>() {
+    push(
+        TypeId::of::<E>(),
+        Lookup::Response(|error| {
+            error
+                .downcast_ref::<E>()
+                .map(|error| error as &(dyn ResponseError + 'static))
+        }),
+    );
+}
+
+/// Registers `E`, responding with the status from `status`.
+///
+/// Use this for errors of other crates, which can't implement [`ResponseError`].
+/// Registering `E` again replaces its previous registration.
+pub fn register_with<E: Error + 'static>(status: fn(&E) -> StatusCode) {
+    push(
+        TypeId::of::<E>(),
+        Lookup::Status(Arc::new(move |error| error.downcast_ref::<E>().map(status))),
+    );
+}
+
+fn push(type_id: TypeId, lookup: Lookup) {
+    let mut registry = REGISTRY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let mut lookups = registry.as_deref().map(<[_]>::to_vec).unwrap_or_default();
+    match lookups.iter_mut().find(|(id, _)| *id == type_id) {
+        Some((_, existing)) => *existing = lookup,
+        None => lookups.push((type_id, lookup)),
+    }
+    *registry = Some(lookups.into());
+}
+
+fn lookups() -> Option<Arc<[Registration]>> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// The status of the first registered or built-in error in the chain starting at `error`.
+#[must_use]
+pub fn status_of(error: &(dyn Error + 'static)) -> Option<StatusCode> {
+    find(error).map(|found| found.status())
+}
+
+/// The first registered or built-in error in the chain starting at `error`.
+pub(crate) fn find<'a>(error: &'a (dyn Error + 'static)) -> Option<Found<'a>> {
+    let lookups = lookups();
+    let lookups = lookups.as_deref().unwrap_or_default();
+    let mut current = Some(error);
+    while let Some(error) = current {
+        let found = lookups
+            .iter()
+            .find_map(|(_, lookup)| match lookup {
+                Lookup::Response(lookup) => lookup(error).map(Found::Response),
+                Lookup::Status(lookup) => lookup(error).map(Found::Status),
+            })
+            .or_else(|| builtin_status(error).map(Found::Status));
+        if found.is_some() {
+            return found;
+        }
+        current = error.source();
+    }
+    None
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "io",
+        feature = "json",
+        feature = "timeout",
+        feature = "tokio",
+        feature = "sqlx",
+        feature = "validator"
+    )),
+    allow(unused_variables)
+)]
+fn builtin_status(error: &(dyn Error + 'static)) -> Option<StatusCode> {
+    #[cfg(feature = "io")]
+    if let Some(error) = error.downcast_ref::<io::Error>() {
+        return Some(crate::wrappers::io_status(error));
+    }
+    #[cfg(feature = "json")]
+    if let Some(error) = error.downcast_ref::<serde_json::Error>() {
+        return Some(crate::wrappers::json_status(error));
+    }
+    #[cfg(feature = "timeout")]
+    if error.is::<crate::wrappers::Timeout>() {
+        return Some(StatusCode::GATEWAY_TIMEOUT);
+    }
+    #[cfg(feature = "tokio")]
+    if error.is::<tokio::time::error::Elapsed>() {
+        return Some(StatusCode::GATEWAY_TIMEOUT);
+    }
+    #[cfg(feature = "sqlx")]
+    if let Some(error) = error.downcast_ref::<sqlx::Error>() {
+        return Some(crate::wrappers::sqlx_status(error));
+    }
+    #[cfg(feature = "validator")]
+    if error.is::<validator::ValidationErrors>()
+        || error.is::<crate::validation::ValidationFailed>()
+    {
+        return Some(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    None
+}
+
+/// Converts fields of `#[status(source)]` to `&dyn Error`.
+///
+/// It's called with method syntax, so smart pointers like `Box<dyn Error>` deref to the error.
+#[doc(hidden)]
+pub trait AsDynError {
+    fn as_dyn_error(&self) -> &(dyn Error + 'static);
+}
+
+impl<T: Error + 'static> AsDynError for T {
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl AsDynError for dyn Error + 'static {
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl AsDynError for dyn Error + Send + 'static {
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl AsDynError for dyn Error + Send + Sync + 'static {
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
@@ -0,0 +1,70 @@
+//! Server-Sent Events frames for errors.
+//!
+//! Once a streaming response has started, its status can't change any more.
+//! [`error_frame`] renders an error as an `event: error` frame instead, with the body of the [`Json`](crate::Json)
+//! derive as its data, so clients get the same `{ "message": .., "code": .. }` as from a regular response.
+//! [`with_error_frames`] applies this to the errors of a stream of frames:
+//!
+//! ```
+//! use actix_web::{web::Bytes, HttpResponse};
+//! use futures_util::stream;
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[error("Feed closed")]
+//! #[status(503)]
+//! #[error_code("feed_closed")]
+//! struct FeedClosed;
+//!
+//! async fn feed() -> HttpResponse {
+//!     let events = stream::iter([
+//!         Ok(Bytes::from_static(b"data: 1\n\n")),
+//!         Err(FeedClosed),
+//!     ]);
+//!     HttpResponse::Ok()
+//!         .content_type("text/event-stream")
+//!         .streaming(actix_web_error::sse::with_error_frames(events))
+//! }
+//! ```
+
+use crate::{__private::JsonErrorSerialize, ErrorCode};
+use actix_web::web::Bytes;
+use futures_util::{
+    future::ready,
+    stream::{Stream, StreamExt},
+};
+use std::{convert::Infallible, fmt::Display};
+
+/// Renders `error` as an `event: error` frame.
+///
+/// The data is the body of [`Json`](crate::Json) with the [`Display`] representation and the [`ErrorCode`],
+/// regardless of the derive of the error.
+pub fn error_frame<E: Display + ErrorCode + ?Sized>(error: &E) -> Bytes {
+    // JSON escapes line breaks, so the data is a single line
+    let body = crate::format::json_body(&JsonErrorSerialize {
+        message: error,
+        code: error.error_code(),
+    });
+
+    let mut frame = b"event: error\ndata: ".to_vec();
+    frame.extend_from_slice(&body);
+    frame.extend_from_slice(b"\n\n");
+    frame.into()
+}
+
+/// Passes the frames of `stream` through, an error is sent as an [`error_frame`] and ends the stream.
+pub fn with_error_frames<S, E>(stream: S) -> impl Stream<Item = Result<Bytes, Infallible>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    E: Display + ErrorCode,
+{
+    stream.scan(false, |failed, item| {
+        if *failed {
+            return ready(None);
+        }
+        let frame = item.unwrap_or_else(|error| {
+            *failed = true;
+            error_frame(&error)
+        });
+        ready(Some(Ok(frame)))
+    })
+}
@@ -0,0 +1,153 @@
+//! Responses for [`validator`] errors.
+//!
+//! [`ValidationFailed`] responds with `422 Unprocessable Entity` and the body of [`Json`](crate::Json),
+//! extended with the field-level errors as `details`:
+//!
+//! ```json
+//! {
+//!   "message": "Validation failed",
+//!   "code": "validation_failed",
+//!   "details": [
+//!     { "pointer": "/address/city", "code": "length", "message": "City is too long" },
+//!     { "pointer": "/tags/1", "code": "required" }
+//!   ]
+//! }
+//! ```
+//!
+//! Errors of a struct-level validation (`__all__`) point to the struct itself.
+//! It can be used as a handler error or wrapped in a derived error with `#[status(transparent)]`:
+//!
+//! ```
+//! use actix_web_error::validation::ValidationFailed;
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SignUp {
+//!     #[validate(email)]
+//!     email: String,
+//! }
+//!
+//! fn sign_up(form: &SignUp) -> Result<(), ValidationFailed> {
+//!     form.validate()?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{__private::JsonErrorSerialize, ErrorCode};
+use actix_web::{
+    body::BoxBody, http::header::ContentType, http::StatusCode, HttpResponse, HttpResponseBuilder,
+    ResponseError,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+/// The error code of [`ValidationFailed`].
+pub const CODE: &str = "validation_failed";
+
+/// Failed validation of a request, responding with `422 Unprocessable Entity`.
+#[derive(Debug, Clone)]
+pub struct ValidationFailed(pub ValidationErrors);
+
+/// A field-level error in the `details` of a [`ValidationFailed`] response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationDetail {
+    /// A JSON pointer to the invalid value in the request.
+    pub pointer: String,
+    /// The code of the validator, e.g. `length`.
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ValidationFailed {
+    /// The field-level errors ordered by their pointer.
+    #[must_use]
+    pub fn details(&self) -> Vec<ValidationDetail> {
+        let mut details = Vec::new();
+        collect_details(&self.0, "", &mut details);
+        // `ValidationErrors` is a hash map, so sort for a stable order
+        details.sort_by(|a, b| a.pointer.cmp(&b.pointer));
+        details
+    }
+}
+
+fn collect_details(errors: &ValidationErrors, parent: &str, details: &mut Vec<ValidationDetail>) {
+    for (field, kind) in errors.errors() {
+        let pointer = if field == "__all__" {
+            parent.to_owned()
+        } else {
+            format!("{parent}/{}", escape_pointer(field))
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                details.extend(errors.iter().map(|error| ValidationDetail {
+                    pointer: pointer.clone(),
+                    code: error.code.to_string(),
+                    message: error.message.as_ref().map(ToString::to_string),
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => collect_details(errors, &pointer, details),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_details(errors, &format!("{pointer}/{index}"), details);
+                }
+            }
+        }
+    }
+}
+
+/// Escapes a reference token of a JSON pointer (RFC 6901).
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+impl From<ValidationErrors> for ValidationFailed {
+    fn from(errors: ValidationErrors) -> Self {
+        Self(errors)
+    }
+}
+
+impl fmt::Display for ValidationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validation failed")
+    }
+}
+
+impl std::error::Error for ValidationFailed {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl ErrorCode for ValidationFailed {
+    fn error_code(&self) -> Option<&'static str> {
+        Some(CODE)
+    }
+}
+
+#[derive(Serialize)]
+struct Body<'a> {
+    #[serde(flatten)]
+    error: JsonErrorSerialize<'a, ValidationFailed>,
+    details: Vec<ValidationDetail>,
+}
+
+impl ResponseError for ValidationFailed {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let body = Body {
+            error: JsonErrorSerialize {
+                message: self,
+                code: Some(CODE),
+            },
+            details: self.details(),
+        };
+        HttpResponseBuilder::new(self.status_code())
+            .content_type(ContentType::json())
+            .body(crate::format::json_body(&body))
+    }
+}
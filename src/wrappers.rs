@@ -0,0 +1,294 @@
+//! Wrappers assigning statuses to errors of common libraries.
+//!
+//! The wrappers implement [`ResponseError`], so derived errors can delegate the status to them
+//! with `#[status(transparent)]`:
+//!
+//! ```
+//! # #[cfg(all(feature = "io", feature = "json"))]
+//! # {
+//! use actix_web_error::wrappers::{IoError, JsonError};
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! enum ImportError {
+//!     #[error("Can't read the file")]
+//!     #[status(transparent)]
+//!     Io(#[from] IoError),
+//!     #[error("Malformed file")]
+//!     #[status(transparent)]
+//!     Json(#[from] JsonError),
+//! }
+//!
+//! fn import(path: &str) -> Result<serde_json::Value, ImportError> {
+//!     let file = std::fs::read(path).map_err(IoError)?;
+//!     Ok(serde_json::from_slice(&file).map_err(JsonError)?)
+//! }
+//! # }
+//! ```
+//!
+//! | Error                                   | Status                                            |
+//! |-----------------------------------------|---------------------------------------------------|
+//! | `IoError` (feature `io`)                | by `ErrorKind`, see `IoError`                     |
+//! | `JsonError` (feature `json`)            | `400`, `500` for I/O errors                       |
+//! | `Timeout` (feature `timeout`)           | `504`                                             |
+//! | `SqlxError` (feature `sqlx`)            | `404` for `RowNotFound`, see `SqlxError`          |
+//!
+//! The response body is the [`Display`](std::fmt::Display) representation of the wrapped error,
+//! as with actix-web's own implementations.
+//! For `5xx` statuses, it's the canonical reason of the status instead, so file paths or SQL
+//! don't leak to clients. The error is logged (feature `log`).
+//!
+//! The wrappers have no error code, so `#[error_code(transparent)]` on a variant wrapping
+//! one omits the code. They implement `utoipa::IntoResponses` (feature `utoipa`) and
+//! `schemars::JsonSchema` (feature `schemars`), so `#[response(transparent)]` can document them.
+
+use actix_web::{
+    http::{header::ContentType, StatusCode},
+    HttpResponse, ResponseError,
+};
+use std::fmt;
+#[cfg(feature = "io")]
+use std::io;
+
+/// Implements `From`, `Display`, `Error` and [`ErrorCode`](crate::ErrorCode) for a wrapper
+/// around an error.
+#[cfg(any(feature = "io", feature = "json", feature = "sqlx"))]
+macro_rules! wrapper {
+    ($wrapper:ident($inner:ty)) => {
+        impl From<$inner> for $wrapper {
+            fn from(error: $inner) -> Self {
+                Self(error)
+            }
+        }
+
+        impl fmt::Display for $wrapper {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl std::error::Error for $wrapper {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        impl crate::ErrorCode for $wrapper {
+            fn error_code(&self) -> Option<&'static str> {
+                None
+            }
+        }
+    };
+}
+
+/// Implements `IntoResponses` (feature `utoipa`) and `JsonSchema` (feature `schemars`)
+/// for a wrapper responding with plain text and `statuses`.
+macro_rules! text_docs {
+    ($wrapper:ident, [$($status:literal),*]) => {
+        #[cfg(feature = "utoipa")]
+        impl utoipa::IntoResponses for $wrapper {
+            fn responses() -> std::collections::BTreeMap<
+                String,
+                utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+            > {
+                crate::openapi::text_responses(stringify!($wrapper), &[$($status),*])
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $wrapper {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed(stringify!($wrapper))
+            }
+
+            fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                String::json_schema(generator)
+            }
+        }
+    };
+}
+
+/// A plain text response with the [`Display`](fmt::Display) representation of `error`,
+/// or the canonical reason of `5xx` statuses.
+fn text_response<E: ResponseError>(error: &E) -> HttpResponse {
+    let status = error.status_code();
+    let body = if status.is_server_error() {
+        #[cfg(feature = "log")]
+        log::error!("{error}");
+        status
+            .canonical_reason()
+            .unwrap_or("Unknown Error")
+            .to_owned()
+    } else {
+        error.to_string()
+    };
+    HttpResponse::build(status)
+        .content_type(ContentType::plaintext())
+        .body(body)
+}
+
+/// An [`io::Error`] with a status by its [`ErrorKind`](io::ErrorKind).
+///
+/// | Kind                                | Status |
+/// |-------------------------------------|--------|
+/// | `NotFound`                          | `404`  |
+/// | `PermissionDenied`                  | `403`  |
+/// | `AlreadyExists`                     | `409`  |
+/// | `InvalidInput`, `InvalidData`       | `400`  |
+/// | `TimedOut`                          | `504`  |
+/// | others                              | `500`  |
+#[cfg(feature = "io")]
+#[derive(Debug)]
+pub struct IoError(pub io::Error);
+
+#[cfg(feature = "io")]
+wrapper!(IoError(io::Error));
+
+#[cfg(feature = "io")]
+impl ResponseError for IoError {
+    fn status_code(&self) -> StatusCode {
+        io_status(&self.0)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        text_response(self)
+    }
+}
+
+#[cfg(feature = "io")]
+text_docs!(IoError, [400, 403, 404, 409, 500, 504]);
+
+#[cfg(feature = "io")]
+pub(crate) fn io_status(error: &io::Error) -> StatusCode {
+    match error.kind() {
+        io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+        io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+        io::ErrorKind::AlreadyExists => StatusCode::CONFLICT,
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => StatusCode::BAD_REQUEST,
+        io::ErrorKind::TimedOut => StatusCode::GATEWAY_TIMEOUT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// A [`serde_json::Error`] responding with `400 Bad Request`.
+///
+/// Errors from reading the input (`Category::Io`) respond with `500 Internal Server Error`.
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub struct JsonError(pub serde_json::Error);
+
+#[cfg(feature = "json")]
+wrapper!(JsonError(serde_json::Error));
+
+#[cfg(feature = "json")]
+impl ResponseError for JsonError {
+    fn status_code(&self) -> StatusCode {
+        json_status(&self.0)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        text_response(self)
+    }
+}
+
+#[cfg(feature = "json")]
+text_docs!(JsonError, [400, 500]);
+
+#[cfg(feature = "json")]
+pub(crate) fn json_status(error: &serde_json::Error) -> StatusCode {
+    if error.is_io() {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+/// An elapsed timeout responding with `504 Gateway Timeout`.
+///
+/// With the `tokio` feature, it's converted from `tokio::time::error::Elapsed`.
+#[cfg(feature = "timeout")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeout;
+
+#[cfg(feature = "timeout")]
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl std::error::Error for Timeout {}
+
+#[cfg(feature = "timeout")]
+impl crate::ErrorCode for Timeout {
+    fn error_code(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl ResponseError for Timeout {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::GATEWAY_TIMEOUT
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        text_response(self)
+    }
+}
+
+#[cfg(feature = "timeout")]
+text_docs!(Timeout, [504]);
+
+#[cfg(all(feature = "timeout", feature = "tokio"))]
+impl From<tokio::time::error::Elapsed> for Timeout {
+    fn from(_: tokio::time::error::Elapsed) -> Self {
+        Self
+    }
+}
+
+/// A [`sqlx::Error`] with a status by its variant.
+///
+/// | Error                                          | Status |
+/// |------------------------------------------------|--------|
+/// | `RowNotFound`                                  | `404`  |
+/// | unique or foreign key violations               | `409`  |
+/// | not-null or check violations                   | `400`  |
+/// | `PoolTimedOut`                                 | `503`  |
+/// | others                                         | `500`  |
+#[cfg(feature = "sqlx")]
+#[derive(Debug)]
+pub struct SqlxError(pub sqlx::Error);
+
+#[cfg(feature = "sqlx")]
+wrapper!(SqlxError(sqlx::Error));
+
+#[cfg(feature = "sqlx")]
+impl ResponseError for SqlxError {
+    fn status_code(&self) -> StatusCode {
+        sqlx_status(&self.0)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        text_response(self)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+text_docs!(SqlxError, [400, 404, 409, 500, 503]);
+
+#[cfg(feature = "sqlx")]
+pub(crate) fn sqlx_status(error: &sqlx::Error) -> StatusCode {
+    use sqlx::error::ErrorKind;
+
+    match error {
+        sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+        sqlx::Error::PoolTimedOut => StatusCode::SERVICE_UNAVAILABLE,
+        sqlx::Error::Database(error) => match error.kind() {
+            ErrorKind::UniqueViolation | ErrorKind::ForeignKeyViolation => StatusCode::CONFLICT,
+            ErrorKind::NotNullViolation | ErrorKind::CheckViolation => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        },
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
@@ -0,0 +1,113 @@
+//! WebSocket close frames for errors.
+//!
+//! With the `ws` feature, the derives implement [`WsClose`], so errors can terminate sessions
+//! (of `actix-ws` or `actix-web-actors`) with a consistent close code.
+//! The code is set with `#[ws_close(4001)]` (`1000..=1003`, `1007..=1014` or `3000..=4999`).
+//! `#[status(transparent)]` errors default to the code of the wrapped error,
+//! others to `1008` (policy violation) for 4xx statuses and `1011` (internal error) for all others.
+//!
+//! ```
+//! use actix_web_error::ws::{CloseCode, WsClose};
+//!
+//! #[derive(Debug, thiserror::Error, actix_web_error::Json)]
+//! #[status(BAD_REQUEST)]
+//! enum SessionError {
+//!     #[error("Token expired")]
+//!     #[status(401)]
+//!     #[ws_close(4001)]
+//!     TokenExpired,
+//!     #[error("Malformed message")]
+//!     Malformed,
+//! }
+//!
+//! let reason = SessionError::TokenExpired.close_reason();
+//! assert_eq!(reason.code, CloseCode::Other(4001));
+//! assert_eq!(reason.description.as_deref(), Some("Token expired"));
+//! assert_eq!(SessionError::Malformed.close_code(), CloseCode::Policy);
+//! ```
+
+use actix_web::{http::StatusCode, ResponseError};
+use std::fmt::Display;
+
+pub use actix_http::ws::{CloseCode, CloseReason};
+
+/// The maximum length of the description in bytes, the payload of a close frame is limited to 125 bytes.
+pub const MAX_DESCRIPTION_LEN: usize = 123;
+
+/// Conversion of an error into the close frame of a WebSocket session.
+pub trait WsClose: Display {
+    /// The close code from `#[ws_close(..)]` or the one derived from the status.
+    fn close_code(&self) -> CloseCode;
+
+    /// The close code with the [`Display`] representation as the description,
+    /// truncated to [`MAX_DESCRIPTION_LEN`] bytes.
+    fn close_reason(&self) -> CloseReason {
+        let mut description = self.to_string();
+        truncate(&mut description, MAX_DESCRIPTION_LEN);
+        CloseReason {
+            code: self.close_code(),
+            description: Some(description),
+        }
+    }
+}
+
+/// The close code of errors without a `#[ws_close(..)]`:
+/// `1008` (policy violation) for 4xx statuses and `1011` (internal error) for all others.
+#[must_use]
+pub fn default_close_code(status: StatusCode) -> CloseCode {
+    if status.is_client_error() {
+        CloseCode::Policy
+    } else {
+        CloseCode::Error
+    }
+}
+
+/// Implements [`WsClose`] with the default close code of the status,
+/// so the errors of this crate can be wrapped by `#[status(transparent)]`.
+macro_rules! status_close {
+    ($($(#[$attr:meta])* $ty:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl WsClose for $ty {
+                fn close_code(&self) -> CloseCode {
+                    default_close_code(self.status_code())
+                }
+            }
+        )*
+    };
+}
+
+status_close!(
+    #[cfg(feature = "io")]
+    crate::wrappers::IoError,
+    #[cfg(feature = "json")]
+    crate::wrappers::JsonError,
+    #[cfg(feature = "timeout")]
+    crate::wrappers::Timeout,
+    #[cfg(feature = "sqlx")]
+    crate::wrappers::SqlxError,
+    #[cfg(feature = "validator")]
+    crate::validation::ValidationFailed,
+    #[cfg(feature = "anyhow")]
+    crate::report::AnyhowResponse,
+    #[cfg(feature = "eyre")]
+    crate::report::EyreResponse,
+);
+
+impl<E: ResponseError + crate::ErrorCode> WsClose for crate::list::ErrorList<E> {
+    fn close_code(&self) -> CloseCode {
+        default_close_code(self.status_code())
+    }
+}
+
+/// Truncates `text` to at most `len` bytes on a character boundary.
+fn truncate(text: &mut String, len: usize) {
+    if text.len() <= len {
+        return;
+    }
+    let mut end = len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+}
@@ -0,0 +1,101 @@
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+#[derive(Debug, thiserror::Error, actix_web_error::AxumJson)]
+#[error("Error: {0}")]
+#[status(400)]
+#[error_code("my_error")]
+struct MyError(&'static str);
+
+#[derive(Debug, thiserror::Error, actix_web_error::AxumJson)]
+#[status(BAD_REQUEST)]
+enum MyEnum {
+    #[error("a")]
+    #[error_code("a")]
+    BadRequest,
+    #[error("b {field}")]
+    Named { field: u8 },
+    #[error("c")]
+    #[status(500)]
+    Internal,
+    #[error("teapot")]
+    #[status(transparent)]
+    Delegate(Teapot),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::AxumText)]
+#[error("Teapot")]
+#[status(418)]
+struct Teapot;
+
+async fn expect_response(
+    error: impl IntoResponse,
+    status: StatusCode,
+    content_type: &str,
+    body: &str,
+) {
+    let response = error.into_response();
+    assert_eq!(response.status(), status);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        content_type
+    );
+    assert_eq!(
+        axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap(),
+        body
+    );
+}
+
+#[actix_web::test]
+async fn basic() {
+    expect_response(
+        MyError("xd"),
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"Error: xd","code":"my_error"}"#,
+    )
+    .await;
+    expect_response(
+        Teapot,
+        StatusCode::IM_A_TEAPOT,
+        "text/plain; charset=utf-8",
+        "Teapot",
+    )
+    .await;
+}
+
+#[actix_web::test]
+async fn basic_enum() {
+    expect_response(
+        MyEnum::BadRequest,
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"a","code":"a"}"#,
+    )
+    .await;
+    expect_response(
+        MyEnum::Named { field: 1 },
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"b 1"}"#,
+    )
+    .await;
+    expect_response(
+        MyEnum::Internal,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "application/json",
+        r#"{"message":"c"}"#,
+    )
+    .await;
+    expect_response(
+        MyEnum::Delegate(Teapot),
+        StatusCode::IM_A_TEAPOT,
+        "application/json",
+        r#"{"message":"teapot"}"#,
+    )
+    .await;
+}
@@ -0,0 +1,33 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header, StatusCode},
+    ResponseError,
+};
+use actix_web_error::ErrorBody;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Cbor)]
+#[error("Sensor {id} is offline")]
+#[status(NOT_FOUND)]
+#[error_code("sensor_offline")]
+struct SensorOffline {
+    id: u32,
+}
+
+#[test]
+fn basic() {
+    let res = SensorOffline { id: 3 }.error_response();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/cbor"
+    );
+    let body = res.into_body().try_into_bytes().unwrap();
+    let body: ErrorBody = ciborium::from_reader(&body[..]).unwrap();
+    assert_eq!(
+        body,
+        ErrorBody {
+            message: "Sensor 3 is offline".to_owned(),
+            code: Some("sensor_offline".to_owned()),
+        }
+    );
+}
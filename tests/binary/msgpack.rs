@@ -0,0 +1,63 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header, StatusCode},
+    ResponseError,
+};
+use actix_web_error::ErrorBody;
+
+#[derive(Debug, thiserror::Error, actix_web_error::MsgPack)]
+#[status(400)]
+enum ServiceError {
+    #[error("Invalid payload: {0}")]
+    #[error_code("invalid_payload")]
+    InvalidPayload(&'static str),
+    #[error("Unavailable")]
+    #[status(SERVICE_UNAVAILABLE)]
+    Unavailable,
+}
+
+fn decode(error: &impl ResponseError) -> ErrorBody {
+    let body = error.error_response().into_body().try_into_bytes().unwrap();
+    rmp_serde::from_slice(&body).unwrap()
+}
+
+#[test]
+fn basic() {
+    let error = ServiceError::InvalidPayload("eof");
+    let res = error.error_response();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/msgpack"
+    );
+    assert_eq!(
+        decode(&error),
+        ErrorBody {
+            message: "Invalid payload: eof".to_owned(),
+            code: Some("invalid_payload".to_owned()),
+        }
+    );
+
+    assert_eq!(
+        ServiceError::Unavailable.status_code(),
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+    assert_eq!(
+        decode(&ServiceError::Unavailable),
+        ErrorBody {
+            message: "Unavailable".to_owned(),
+            code: None,
+        }
+    );
+}
+
+#[test]
+fn named_fields() {
+    // a map, like the JSON object
+    let body = ServiceError::Unavailable
+        .error_response()
+        .into_body()
+        .try_into_bytes()
+        .unwrap();
+    assert_eq!(&body[..], b"\x81\xa7message\xabUnavailable");
+}
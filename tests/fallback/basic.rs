@@ -0,0 +1,61 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header, StatusCode},
+    test, web, App, HttpResponse,
+};
+
+async fn ok() -> HttpResponse {
+    HttpResponse::Ok().finish()
+}
+
+async fn teapot() -> HttpResponse {
+    HttpResponse::ImATeapot().body("short and stout")
+}
+
+#[actix_web::test]
+async fn default_service() {
+    let app = test::init_service(
+        App::new().default_service(web::to(actix_web_error::fallback::default_service)),
+    )
+    .await;
+
+    let res = test::call_service(&app, test::TestRequest::get().uri("/nope").to_request()).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Not Found"}"#
+    );
+}
+
+#[actix_web::test]
+async fn error_handlers() {
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web_error::fallback::error_handlers())
+            .service(web::resource("/").route(web::get().to(ok)))
+            .route("/teapot", web::get().to(teapot)),
+    )
+    .await;
+
+    let res = test::call_service(&app, test::TestRequest::get().uri("/nope").to_request()).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Not Found"}"#
+    );
+
+    let res = test::call_service(&app, test::TestRequest::post().uri("/").to_request()).await;
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Method Not Allowed"}"#
+    );
+
+    let res = test::call_service(&app, test::TestRequest::get().uri("/teapot").to_request()).await;
+    assert_eq!(res.status(), StatusCode::IM_A_TEAPOT);
+    assert_eq!(res.into_body().try_into_bytes().unwrap(), "short and stout");
+}
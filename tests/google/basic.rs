@@ -0,0 +1,168 @@
+use crate::common::expect_response;
+use actix_web::{http::header, http::StatusCode, ResponseError};
+use actix_web_error::{google::GoogleErrorBody, grpc::GrpcCode};
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[error("Book {0} not found")]
+#[status(NOT_FOUND)]
+#[error_code("book_not_found")]
+struct BookNotFound(u32);
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[error("Quota exceeded")]
+#[status(429)]
+struct QuotaExceeded;
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[status(BAD_REQUEST)]
+enum LibraryError {
+    #[error("Invalid title")]
+    InvalidTitle,
+    #[error("Shelf {shelf} is full")]
+    #[grpc(code = FailedPrecondition)]
+    #[error_code("shelf_full")]
+    ShelfFull { shelf: u32 },
+    #[error("Book already borrowed")]
+    #[status(409)]
+    #[grpc(code = Aborted)]
+    Borrowed,
+    #[error(transparent)]
+    #[status(transparent)]
+    Book(#[from] BookNotFound),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[status(500)]
+#[grpc(code = DataLoss)]
+enum StorageError {
+    #[error("Corrupted page")]
+    Corrupted,
+    #[error("Disk unavailable")]
+    #[grpc(code = Unavailable)]
+    Unavailable,
+}
+
+#[test]
+fn basic() {
+    expect_response(
+        &BookNotFound(7),
+        StatusCode::NOT_FOUND,
+        r#"{"error":{"code":404,"status":"NOT_FOUND","message":"Book 7 not found","details":[{"@type":"type.googleapis.com/google.rpc.ErrorInfo","reason":"book_not_found"}]}}"#,
+    );
+    expect_response(
+        &QuotaExceeded,
+        StatusCode::TOO_MANY_REQUESTS,
+        r#"{"error":{"code":429,"status":"RESOURCE_EXHAUSTED","message":"Quota exceeded"}}"#,
+    );
+    expect_response(
+        &LibraryError::InvalidTitle,
+        StatusCode::BAD_REQUEST,
+        r#"{"error":{"code":400,"status":"INVALID_ARGUMENT","message":"Invalid title"}}"#,
+    );
+    expect_response(
+        &LibraryError::ShelfFull { shelf: 3 },
+        StatusCode::BAD_REQUEST,
+        r#"{"error":{"code":400,"status":"FAILED_PRECONDITION","message":"Shelf 3 is full","details":[{"@type":"type.googleapis.com/google.rpc.ErrorInfo","reason":"shelf_full"}]}}"#,
+    );
+    expect_response(
+        &LibraryError::Borrowed,
+        StatusCode::CONFLICT,
+        r#"{"error":{"code":409,"status":"ABORTED","message":"Book already borrowed"}}"#,
+    );
+    expect_response(
+        &LibraryError::Book(BookNotFound(2)),
+        StatusCode::NOT_FOUND,
+        r#"{"error":{"code":404,"status":"NOT_FOUND","message":"Book 2 not found"}}"#,
+    );
+}
+
+#[test]
+fn container_grpc_code() {
+    expect_response(
+        &StorageError::Corrupted,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        r#"{"error":{"code":500,"status":"DATA_LOSS","message":"Corrupted page"}}"#,
+    );
+    expect_response(
+        &StorageError::Unavailable,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        r#"{"error":{"code":500,"status":"UNAVAILABLE","message":"Disk unavailable"}}"#,
+    );
+}
+
+#[test]
+fn content_type() {
+    let res = BookNotFound(1).error_response();
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+}
+
+#[actix_web::test]
+async fn deserialize() {
+    let body = actix_web::body::to_bytes(BookNotFound(5).error_response().into_body())
+        .await
+        .unwrap();
+    let body: GoogleErrorBody = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body.error.code, 404);
+    assert_eq!(body.error.status, GrpcCode::NotFound.name());
+    assert_eq!(body.error.details.len(), 1);
+}
+
+#[test]
+fn http_mapping() {
+    for (status, code) in [
+        (StatusCode::OK, GrpcCode::Ok),
+        (StatusCode::UNPROCESSABLE_ENTITY, GrpcCode::InvalidArgument),
+        (StatusCode::UNAUTHORIZED, GrpcCode::Unauthenticated),
+        (StatusCode::FORBIDDEN, GrpcCode::PermissionDenied),
+        (StatusCode::GONE, GrpcCode::NotFound),
+        (StatusCode::METHOD_NOT_ALLOWED, GrpcCode::Unimplemented),
+        (StatusCode::GATEWAY_TIMEOUT, GrpcCode::DeadlineExceeded),
+        (StatusCode::CONFLICT, GrpcCode::AlreadyExists),
+        (StatusCode::RANGE_NOT_SATISFIABLE, GrpcCode::OutOfRange),
+        (StatusCode::SERVICE_UNAVAILABLE, GrpcCode::Unavailable),
+        (StatusCode::IM_A_TEAPOT, GrpcCode::FailedPrecondition),
+        (StatusCode::INSUFFICIENT_STORAGE, GrpcCode::Internal),
+        (StatusCode::MOVED_PERMANENTLY, GrpcCode::Unknown),
+    ] {
+        assert_eq!(GrpcCode::from_http_status(status), code, "{status}");
+    }
+    assert_eq!(i32::from(GrpcCode::Unauthenticated), 16);
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[error("Shelf {0} is locked")]
+#[status(409)]
+#[grpc(code = Aborted)]
+#[message(key = "errors.shelf_locked")]
+struct ShelfLocked(u32);
+
+async fn shelf_locked() -> Result<&'static str, ShelfLocked> {
+    Err(ShelfLocked(4))
+}
+
+#[actix_web::test]
+async fn localized() {
+    use actix_web::{body::MessageBody, test, web, App};
+    use actix_web_error::i18n::{Localize, MapTranslator};
+
+    let translator =
+        MapTranslator::new().with("de", "errors.shelf_locked", "Regal {0} ist gesperrt");
+    let app = test::init_service(
+        App::new()
+            .wrap(Localize::new(translator))
+            .route("/", web::get().to(shelf_locked)),
+    )
+    .await;
+    let req = test::TestRequest::get()
+        .insert_header((header::ACCEPT_LANGUAGE, "de"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"error":{"code":409,"status":"ABORTED","message":"Regal 4 ist gesperrt"}}"#
+    );
+}
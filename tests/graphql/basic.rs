@@ -0,0 +1,94 @@
+use actix_web::ResponseError;
+use async_graphql::ErrorExtensions;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Order {id} not found")]
+#[status(NOT_FOUND)]
+#[error_code("order_not_found")]
+struct OrderNotFound {
+    #[detail]
+    id: u32,
+    internal: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Limit {
+    max: u32,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum CheckoutError {
+    #[error("Too many items: {0}")]
+    #[error_code("too_many_items")]
+    TooManyItems(#[detail] usize, #[detail] Limit),
+    #[error("Coupon {coupon} expired")]
+    CouponExpired {
+        #[detail]
+        coupon: String,
+        reason: String,
+    },
+    #[error("Payment failed")]
+    #[status(502)]
+    PaymentFailed,
+    #[error(transparent)]
+    #[status(transparent)]
+    Order(#[from] OrderNotFound),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Invalid {0}")]
+#[status(422)]
+struct Invalid<T: std::fmt::Debug + std::fmt::Display>(#[detail] T);
+
+fn extensions(error: &impl ErrorExtensions) -> Value {
+    serde_json::to_value(error.extend().extensions).unwrap()
+}
+
+#[test]
+fn extensions_struct() {
+    let error = OrderNotFound {
+        id: 7,
+        internal: "db",
+    };
+    assert_eq!(error.extend().message, "Order 7 not found");
+    assert_eq!(
+        extensions(&error),
+        json!({ "status": 404, "code": "order_not_found", "id": 7 })
+    );
+}
+
+#[test]
+fn extensions_enum() {
+    assert_eq!(
+        extensions(&CheckoutError::TooManyItems(12, Limit { max: 10 })),
+        json!({ "status": 400, "code": "too_many_items", "0": 12, "1": { "max": 10 } })
+    );
+    assert_eq!(
+        extensions(&CheckoutError::CouponExpired {
+            coupon: "SUMMER".to_owned(),
+            reason: "date".to_owned(),
+        }),
+        json!({ "status": 400, "coupon": "SUMMER" })
+    );
+    assert_eq!(
+        extensions(&CheckoutError::PaymentFailed),
+        json!({ "status": 502 })
+    );
+    let error = CheckoutError::Order(OrderNotFound {
+        id: 1,
+        internal: "",
+    });
+    assert_eq!(error.status_code().as_u16(), 404);
+    assert_eq!(extensions(&error), json!({ "status": 404 }));
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        extensions(&Invalid("name")),
+        json!({ "status": 422, "0": "name" })
+    );
+}
@@ -0,0 +1,106 @@
+use actix_web::ResponseError;
+use actix_web_error::grpc::ERROR_CODE_METADATA;
+use tonic::{Code, Status};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Account {0} not found")]
+#[status(NOT_FOUND)]
+#[error_code("account_not_found")]
+#[grpc]
+struct AccountNotFound(u32);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Slow down")]
+#[status(429)]
+#[grpc(code = Unavailable)]
+struct SlowDown;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+#[grpc]
+enum TransferError {
+    #[error("Invalid amount: {0}")]
+    #[error_code("invalid_amount")]
+    InvalidAmount(i64),
+    #[error("Insufficient funds")]
+    #[grpc(code = FailedPrecondition)]
+    #[error_code("insufficient_funds")]
+    InsufficientFunds,
+    #[error("Transfer timed out")]
+    #[status(504)]
+    Timeout,
+    #[error(transparent)]
+    #[status(transparent)]
+    Account(#[from] AccountNotFound),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Wrapped: {0}")]
+#[status(transparent)]
+#[grpc]
+struct Wrapped<E: ResponseError>(E);
+
+/// Without `#[grpc]`, the conversion is left to the user.
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Quota exceeded")]
+#[status(429)]
+struct QuotaExceeded;
+
+impl From<QuotaExceeded> for Status {
+    fn from(error: QuotaExceeded) -> Self {
+        Status::resource_exhausted(error.to_string())
+    }
+}
+
+fn error_code(status: &Status) -> Option<&str> {
+    status
+        .metadata()
+        .get(ERROR_CODE_METADATA)
+        .map(|value| value.to_str().unwrap())
+}
+
+#[test]
+fn from_status() {
+    let status = Status::from(AccountNotFound(3));
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(status.message(), "Account 3 not found");
+    assert_eq!(error_code(&status), Some("account_not_found"));
+
+    let status = Status::from(TransferError::InvalidAmount(-1));
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(status.message(), "Invalid amount: -1");
+    assert_eq!(error_code(&status), Some("invalid_amount"));
+
+    let status = Status::from(TransferError::Timeout);
+    assert_eq!(status.code(), Code::DeadlineExceeded);
+    assert_eq!(error_code(&status), None);
+
+    let status = Status::from(TransferError::Account(AccountNotFound(1)));
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(error_code(&status), None);
+}
+
+#[test]
+fn grpc_code() {
+    let status = Status::from(SlowDown);
+    assert_eq!(status.code(), Code::Unavailable);
+    assert_eq!(status.message(), "Slow down");
+
+    let status = Status::from(TransferError::InsufficientFunds);
+    assert_eq!(status.code(), Code::FailedPrecondition);
+    assert_eq!(error_code(&status), Some("insufficient_funds"));
+}
+
+#[test]
+fn generic() {
+    let status = Status::from(Wrapped(AccountNotFound(9)));
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(status.message(), "Wrapped: Account 9 not found");
+}
+
+#[test]
+fn opt_in() {
+    let status = Status::from(QuotaExceeded);
+    assert_eq!(status.code(), Code::ResourceExhausted);
+    assert_eq!(error_code(&status), None);
+}
@@ -0,0 +1,114 @@
+use crate::common::expect_response;
+use actix_web::{body::MessageBody, http::header, http::StatusCode, ResponseError};
+use actix_web_error::html::ErrorPage;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[error("The page <{0}> doesn't exist")]
+#[status(NOT_FOUND)]
+struct PageNotFound(&'static str);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[status(400)]
+enum FormError {
+    #[error("Name & email are required")]
+    #[error_code("missing_fields")]
+    MissingFields,
+    #[error("Something went wrong")]
+    #[status(500)]
+    Internal,
+}
+
+fn error_page(page: &ErrorPage) -> String {
+    format!(
+        "<main data-status=\"{}\"><h1>{}</h1><p>{}</p><p>{}</p></main>",
+        page.status.as_u16(),
+        page.title,
+        page.message,
+        page.code.as_deref().unwrap_or("-"),
+    )
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[error("\"{0}\" isn't allowed")]
+#[status(403)]
+#[error_code("<forbidden>")]
+#[html(template = error_page)]
+struct Forbidden(&'static str);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[status(403)]
+enum AccessError {
+    #[error("Locked")]
+    #[html(template = error_page)]
+    Locked { until: u64 },
+    #[error("Denied")]
+    Denied,
+}
+
+#[test]
+fn default_template() {
+    expect_response(
+        &PageNotFound("<script>"),
+        StatusCode::NOT_FOUND,
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>404 Not Found</title>
+</head>
+<body>
+<h1>404 Not Found</h1>
+<p>The page &lt;&lt;script&gt;&gt; doesn&#39;t exist</p>
+</body>
+</html>
+",
+    );
+    expect_response(
+        &FormError::MissingFields,
+        StatusCode::BAD_REQUEST,
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>400 Bad Request</title>
+</head>
+<body>
+<h1>400 Bad Request</h1>
+<p>Name &amp; email are required</p>
+<p><code>missing_fields</code></p>
+</body>
+</html>
+",
+    );
+
+    let res = FormError::Internal.error_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+}
+
+#[test]
+fn custom_template() {
+    expect_response(
+        &Forbidden("<b>"),
+        StatusCode::FORBIDDEN,
+        "<main data-status=\"403\"><h1>403 Forbidden</h1><p>&quot;&lt;b&gt;&quot; isn&#39;t allowed</p><p>&lt;forbidden&gt;</p></main>",
+    );
+}
+
+#[test]
+fn variant_template() {
+    expect_response(
+        &AccessError::Locked { until: 0 },
+        StatusCode::FORBIDDEN,
+        "<main data-status=\"403\"><h1>403 Forbidden</h1><p>Locked</p><p>-</p></main>",
+    );
+    let body = AccessError::Denied
+        .error_response()
+        .into_body()
+        .try_into_bytes()
+        .unwrap();
+    assert!(body.starts_with(b"<!DOCTYPE html>"));
+}
@@ -0,0 +1,88 @@
+use actix_web_error::IntoHttpResponse;
+use http::{header, Response, StatusCode};
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpJson)]
+#[error("Error: {0}")]
+#[status(400)]
+#[error_code("my_error")]
+struct MyError(&'static str);
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpJson)]
+#[status(BAD_REQUEST)]
+enum MyEnum {
+    #[error("a")]
+    #[error_code("a")]
+    BadRequest,
+    #[error("b {field}")]
+    Named { field: u8 },
+    #[error("c")]
+    #[status(500)]
+    Internal,
+    #[error("teapot")]
+    #[status(transparent)]
+    Delegate(Teapot),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpText)]
+#[error("Teapot")]
+#[status(418)]
+struct Teapot;
+
+pub(crate) fn expect_response<E>(error: &E, status: StatusCode, content_type: &str, body: &str)
+where
+    E: IntoHttpResponse,
+    for<'a> Response<Vec<u8>>: From<&'a E>,
+{
+    assert_eq!(error.status_code(), status);
+    let response = Response::from(error);
+    assert_eq!(response.status(), status);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        content_type
+    );
+    assert_eq!(response.into_body(), body.as_bytes());
+}
+
+#[test]
+fn basic() {
+    expect_response(
+        &MyError("xd"),
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"Error: xd","code":"my_error"}"#,
+    );
+    expect_response(
+        &Teapot,
+        StatusCode::IM_A_TEAPOT,
+        "text/plain; charset=utf-8",
+        "Teapot",
+    );
+}
+
+#[test]
+fn basic_enum() {
+    expect_response(
+        &MyEnum::BadRequest,
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"a","code":"a"}"#,
+    );
+    expect_response(
+        &MyEnum::Named { field: 1 },
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"b 1"}"#,
+    );
+    expect_response(
+        &MyEnum::Internal,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "application/json",
+        r#"{"message":"c"}"#,
+    );
+    expect_response(
+        &MyEnum::Delegate(Teapot),
+        StatusCode::IM_A_TEAPOT,
+        "application/json",
+        r#"{"message":"teapot"}"#,
+    );
+}
@@ -0,0 +1,48 @@
+use super::basic::expect_response;
+use http::StatusCode;
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpText)]
+#[error("my error")]
+#[status(503)]
+struct MyStruct;
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpJson)]
+#[error("Error: {0}")]
+#[status(transparent)]
+struct MyError<T>(T);
+
+#[derive(Debug, thiserror::Error, actix_web_error::HttpJson)]
+#[status(400)]
+enum MyEnum<T> {
+    #[error("Bad")]
+    Bad,
+    #[error("Delegate")]
+    #[status(transparent)]
+    Delegate(T),
+}
+
+#[test]
+fn structs() {
+    expect_response(
+        &MyError(MyStruct),
+        StatusCode::SERVICE_UNAVAILABLE,
+        "application/json",
+        r#"{"message":"Error: my error"}"#,
+    );
+}
+
+#[test]
+fn enums() {
+    expect_response(
+        &MyEnum::<MyStruct>::Bad,
+        StatusCode::BAD_REQUEST,
+        "application/json",
+        r#"{"message":"Bad"}"#,
+    );
+    expect_response(
+        &MyEnum::Delegate(MyStruct),
+        StatusCode::SERVICE_UNAVAILABLE,
+        "application/json",
+        r#"{"message":"Delegate"}"#,
+    );
+}
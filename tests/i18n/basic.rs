@@ -0,0 +1,235 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header, StatusCode},
+    test, web, App,
+};
+use actix_web_error::i18n::{Localize, MapTranslator};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum ItemError {
+    #[error("Item {id} not found")]
+    #[status(NOT_FOUND)]
+    #[error_code("item_not_found")]
+    #[message(key = "errors.item_not_found")]
+    NotFound { id: u32 },
+    #[error("Invalid name: {0} ({1} characters)")]
+    #[message(key = "errors.invalid_name")]
+    InvalidName(String, usize),
+    #[error("Not translated")]
+    Untranslated,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Too many requests")]
+#[status(429)]
+#[message(key = "errors.rate_limited")]
+struct RateLimited;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Forbidden: {reason}")]
+#[status(403)]
+#[error_code("forbidden")]
+#[message(key = "errors.forbidden")]
+struct Forbidden {
+    reason: &'static str,
+}
+
+async fn not_found() -> Result<&'static str, ItemError> {
+    Err(ItemError::NotFound { id: 42 })
+}
+
+async fn invalid_name() -> Result<&'static str, ItemError> {
+    Err(ItemError::InvalidName("{x}".to_owned(), 3))
+}
+
+async fn untranslated() -> Result<&'static str, ItemError> {
+    Err(ItemError::Untranslated)
+}
+
+async fn rate_limited() -> Result<&'static str, RateLimited> {
+    Err(RateLimited)
+}
+
+async fn forbidden() -> Result<&'static str, Forbidden> {
+    Err(Forbidden { reason: "banned" })
+}
+
+fn translator() -> MapTranslator {
+    MapTranslator::new()
+        .with("de", "errors.item_not_found", "Eintrag {id} nicht gefunden")
+        .with("de-AT", "errors.item_not_found", "Eintrag {id} net gfunden")
+        .with("fr", "errors.item_not_found", "Élément {id} introuvable")
+        .with(
+            "de",
+            "errors.invalid_name",
+            "Ungültiger Name: {0} ({1} Zeichen, {{{unknown}}})",
+        )
+        .with("de", "errors.rate_limited", "Zu viele Anfragen")
+        .with("en", "errors.forbidden", "Access denied: {reason}")
+}
+
+macro_rules! app {
+    ($localize:expr) => {
+        test::init_service(
+            App::new()
+                .wrap($localize)
+                .route("/not_found", web::get().to(not_found))
+                .route("/invalid_name", web::get().to(invalid_name))
+                .route("/untranslated", web::get().to(untranslated))
+                .route("/rate_limited", web::get().to(rate_limited))
+                .route("/forbidden", web::get().to(forbidden)),
+        )
+        .await
+    };
+}
+
+fn request(uri: &str, accept_language: &str) -> test::TestRequest {
+    test::TestRequest::get()
+        .uri(uri)
+        .insert_header((header::ACCEPT_LANGUAGE, accept_language))
+}
+
+#[actix_web::test]
+async fn json() {
+    let app = app!(Localize::new(translator()));
+
+    let res = test::call_service(&app, request("/not_found", "fr-CH, de;q=0.8").to_request()).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "fr");
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Élément 42 introuvable","code":"item_not_found"}"#
+    );
+
+    let res = test::call_service(&app, request("/not_found", "de-AT").to_request()).await;
+    assert_eq!(
+        res.headers().get(header::CONTENT_LANGUAGE).unwrap(),
+        "de-AT"
+    );
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Eintrag 42 net gfunden","code":"item_not_found"}"#
+    );
+
+    let res = test::call_service(&app, request("/invalid_name", "en, de;q=0.5").to_request()).await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Ungültiger Name: {x} (3 Zeichen, {{unknown}})"}"#
+    );
+
+    let res = test::call_service(&app, request("/forbidden", "de").to_request()).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Forbidden: banned","code":"forbidden"}"#
+    );
+}
+
+#[actix_web::test]
+async fn text() {
+    let app = app!(Localize::new(translator()));
+
+    let res = test::call_service(&app, request("/rate_limited", "de-DE").to_request()).await;
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "de");
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        "Zu viele Anfragen"
+    );
+
+    let res = test::call_service(
+        &app,
+        test::TestRequest::get().uri("/rate_limited").to_request(),
+    )
+    .await;
+    assert!(res.headers().get(header::CONTENT_LANGUAGE).is_none());
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        "Too many requests"
+    );
+}
+
+#[actix_web::test]
+async fn no_translation() {
+    let app = app!(Localize::new(translator()).default_locale("de"));
+
+    let res = test::call_service(&app, request("/untranslated", "de").to_request()).await;
+    assert!(res.headers().get(header::CONTENT_LANGUAGE).is_none());
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Not translated"}"#
+    );
+
+    // q=0 excludes a locale
+    let res = test::call_service(&app, request("/forbidden", "de, en;q=0").to_request()).await;
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Forbidden: banned","code":"forbidden"}"#
+    );
+}
+
+#[actix_web::test]
+async fn default_locale() {
+    let app = app!(Localize::new(translator()).default_locale("en"));
+
+    let res = test::call_service(&app, request("/forbidden", "it, *").to_request()).await;
+    assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "en");
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Access denied: banned","code":"forbidden"}"#
+    );
+
+    let res = test::call_service(
+        &app,
+        test::TestRequest::get().uri("/not_found").to_request(),
+    )
+    .await;
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Item 42 not found","code":"item_not_found"}"#
+    );
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[error("Page not found")]
+#[status(404)]
+#[message(key = "errors.page_not_found")]
+struct PageNotFound;
+
+async fn page_not_found() -> Result<&'static str, PageNotFound> {
+    Err(PageNotFound)
+}
+
+#[actix_web::test]
+async fn html() {
+    let app = test::init_service(
+        App::new()
+            .wrap(Localize::new(MapTranslator::new().with(
+                "de",
+                "errors.page_not_found",
+                "Seite <nicht> gefunden",
+            )))
+            .route("/", web::get().to(page_not_found)),
+    )
+    .await;
+
+    let res = test::call_service(&app, request("/", "de").to_request()).await;
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let body = res.into_body().try_into_bytes().unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("<title>404 Not Found</title>"));
+    assert!(body.contains("<p>Seite &lt;nicht&gt; gefunden</p>"));
+}
@@ -0,0 +1,57 @@
+use actix_web::{body::MessageBody, http::header, test, web, App};
+use actix_web_error::i18n::{Localize, MapTranslator};
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Invalid value {value}")]
+#[status(422)]
+#[message(key = "errors.invalid_value")]
+struct InvalidValue<T: Debug + Display> {
+    value: T,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+enum GenericError<T: Debug + Display> {
+    #[error("Missing {0}")]
+    #[status(400)]
+    #[message(key = "errors.missing")]
+    Missing(T),
+}
+
+async fn invalid_value() -> Result<&'static str, InvalidValue<f32>> {
+    Err(InvalidValue { value: 1.5 })
+}
+
+async fn missing() -> Result<&'static str, GenericError<&'static str>> {
+    Err(GenericError::Missing("name"))
+}
+
+#[actix_web::test]
+async fn generic() {
+    let app = test::init_service(
+        App::new()
+            .wrap(Localize::new(
+                MapTranslator::new()
+                    .with("de", "errors.invalid_value", "Ungültiger Wert {value}")
+                    .with("de", "errors.missing", "{0} fehlt"),
+            ))
+            .route("/invalid_value", web::get().to(invalid_value))
+            .route("/missing", web::get().to(missing)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/invalid_value")
+        .insert_header((header::ACCEPT_LANGUAGE, "de"));
+    let res = test::call_service(&app, req.to_request()).await;
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Ungültiger Wert 1.5"}"#
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/missing")
+        .insert_header((header::ACCEPT_LANGUAGE, "de"));
+    let res = test::call_service(&app, req.to_request()).await;
+    assert_eq!(res.into_body().try_into_bytes().unwrap(), "name fehlt");
+}
@@ -42,12 +42,12 @@ fn basic() {
     expect_response(
         &MyError("xd"),
         StatusCode::BAD_REQUEST,
-        r#"{"error":"Error: xd"}"#,
+        r#"{"message":"Error: xd"}"#,
     );
     expect_response(
         &MyError2("xd"),
         StatusCode::BAD_REQUEST,
-        r#"{"error":"Error: xd"}"#,
+        r#"{"message":"Error: xd"}"#,
     );
 }
 
@@ -58,31 +58,31 @@ fn basic_enum() {
     expect_response(
         &MyEnum::BadRequest,
         StatusCode::BAD_REQUEST,
-        r#"{"error":"a"}"#,
+        r#"{"message":"a"}"#,
     );
     expect_response(
         &MyEnum::AnotherBadRequest,
         StatusCode::BAD_REQUEST,
-        r#"{"error":"b"}"#,
+        r#"{"message":"b"}"#,
     );
     expect_response(
         &MyEnum::Internal,
         StatusCode::INTERNAL_SERVER_ERROR,
-        r#"{"error":"c"}"#,
+        r#"{"message":"c"}"#,
     );
     expect_response(
         &MyEnum2::BadRequest,
         StatusCode::BAD_REQUEST,
-        r#"{"error":"a"}"#,
+        r#"{"message":"a"}"#,
     );
     expect_response(
         &MyEnum2::AnotherBadRequest,
         StatusCode::BAD_REQUEST,
-        r#"{"error":"b"}"#,
+        r#"{"message":"b"}"#,
     );
     expect_response(
         &MyEnum2::Internal,
         StatusCode::INTERNAL_SERVER_ERROR,
-        r#"{"error":"c"}"#,
+        r#"{"message":"c"}"#,
     );
 }
@@ -0,0 +1,88 @@
+use actix_web_error::ErrorBody;
+
+#[derive(Debug, PartialEq, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+#[decode]
+enum MyEnum {
+    #[error("a")]
+    #[error_code("a")]
+    WithCode,
+    #[error("b")]
+    WithoutCode,
+    #[error("c: {0}")]
+    #[error_code("c")]
+    WithField(u8),
+    #[error("d")]
+    #[status(404)]
+    NotFound,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error, actix_web_error::Json)]
+#[error("Item not found")]
+#[status(404)]
+#[decode]
+#[error_code("item_not_found")]
+struct MyError;
+
+fn body(message: &str, code: Option<&str>) -> ErrorBody {
+    serde_json::from_value(serde_json::json!({ "message": message, "code": code })).unwrap()
+}
+
+#[test]
+fn decode_enum() {
+    assert_eq!(MyEnum::try_from(body("?", Some("a"))), Ok(MyEnum::WithCode));
+    assert_eq!(MyEnum::try_from(body("b", None)), Ok(MyEnum::WithoutCode));
+    assert_eq!(MyEnum::try_from(body("d", None)), Ok(MyEnum::NotFound));
+    assert_eq!(MyEnum::WithField(1).to_string(), "c: 1");
+    assert_eq!(
+        MyEnum::try_from(body("c: 1", Some("c"))),
+        Err(body("c: 1", Some("c")))
+    );
+    assert_eq!(
+        MyEnum::try_from(body("b", Some("x"))),
+        Err(body("b", Some("x")))
+    );
+
+    assert_eq!(
+        MyEnum::from_response(400, body("a", Some("a"))),
+        Ok(MyEnum::WithCode)
+    );
+    assert_eq!(
+        MyEnum::from_response(404, body("d", None)),
+        Ok(MyEnum::NotFound)
+    );
+    assert_eq!(
+        MyEnum::from_response(400, body("d", None)),
+        Err(body("d", None))
+    );
+}
+
+#[test]
+fn decode_struct() {
+    let json = r#"{"message":"Item not found","code":"item_not_found"}"#;
+    let decoded: ErrorBody = serde_json::from_str(json).unwrap();
+    assert_eq!(MyError::from_response(404, decoded.clone()), Ok(MyError));
+    assert_eq!(MyError::from_response(400, decoded.clone()), Err(decoded));
+    assert_eq!(
+        MyError::try_from(body("Item not found", None)),
+        Err(body("Item not found", None))
+    );
+}
+
+/// Without `#[decode]`, nothing clashes with the type's own `from_response`.
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Upstream failed")]
+#[status(BAD_GATEWAY)]
+struct UpstreamError;
+
+impl UpstreamError {
+    fn from_response(status: u16) -> Option<Self> {
+        (status == 502).then_some(Self)
+    }
+}
+
+#[test]
+fn opt_in() {
+    assert!(UpstreamError::from_response(502).is_some());
+    assert!(UpstreamError::from_response(500).is_none());
+}
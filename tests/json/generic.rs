@@ -14,7 +14,11 @@ struct MyStruct;
 
 impl MyTrait for MyStruct {}
 
-impl ResponseError for MyStruct {}
+impl ResponseError for MyStruct {
+    fn status_code(&self) -> StatusCode {
+        Self::status()
+    }
+}
 
 #[derive(Debug, thiserror::Error, actix_web_error::Json)]
 #[error("Error: {0}")]
@@ -36,7 +40,7 @@ fn structs() {
     expect_response(
         &MyError(MyStruct),
         StatusCode::INTERNAL_SERVER_ERROR,
-        r#"{"error":"Error: my error"}"#,
+        r#"{"message":"Error: my error"}"#,
     );
 }
 
@@ -45,11 +49,11 @@ fn enums() {
     expect_response(
         &MyEnum::<MyStruct>::Bad,
         StatusCode::BAD_REQUEST,
-        r#"{"error":"Bad"}"#,
+        r#"{"message":"Bad"}"#,
     );
     expect_response(
         &MyEnum::Delegate(MyStruct),
         StatusCode::INTERNAL_SERVER_ERROR,
-        r#"{"error":"Delegate"}"#,
+        r#"{"message":"Delegate"}"#,
     );
 }
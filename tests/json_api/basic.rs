@@ -0,0 +1,135 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header, StatusCode},
+    ResponseError,
+};
+use actix_web_error::json_api::{JsonApiDocument, JsonApiError, JsonApiSource};
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, thiserror::Error, actix_web_error::JsonApi)]
+#[status(422)]
+enum ArticleError {
+    #[error("The title must not be empty")]
+    #[error_code("empty_title")]
+    #[json_api(pointer = "/data/attributes/title")]
+    EmptyTitle,
+    #[error("Unknown sort field {0}")]
+    #[status(400)]
+    UnknownSort(#[json_api(parameter)] &'static str, &'static str),
+    #[error("Invalid attribute")]
+    InvalidAttribute {
+        #[json_api(pointer)]
+        pointer: String,
+    },
+    #[error("Conflict")]
+    #[status(409)]
+    #[json_api(parameter = "filter")]
+    Conflict,
+    #[error("Internal")]
+    #[status(500)]
+    Internal,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::JsonApi)]
+#[error("Invalid value {0}")]
+#[status(BAD_REQUEST)]
+#[error_code("invalid_value")]
+struct InvalidValue<T: Debug + Display>(#[json_api(pointer)] T);
+
+fn document(error: &impl ResponseError) -> serde_json::Value {
+    let res = error.error_response();
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/vnd.api+json"
+    );
+    serde_json::from_slice(&res.into_body().try_into_bytes().unwrap()).unwrap()
+}
+
+#[test]
+fn basic() {
+    assert_eq!(
+        ArticleError::EmptyTitle.status_code(),
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(
+        document(&ArticleError::EmptyTitle),
+        serde_json::json!({
+            "errors": [{
+                "status": "422",
+                "code": "empty_title",
+                "title": "Unprocessable Entity",
+                "detail": "The title must not be empty",
+                "source": { "pointer": "/data/attributes/title" },
+            }]
+        })
+    );
+    assert_eq!(
+        document(&ArticleError::UnknownSort("sort", "nope")),
+        serde_json::json!({
+            "errors": [{
+                "status": "400",
+                "title": "Bad Request",
+                "detail": "Unknown sort field sort",
+                "source": { "parameter": "sort" },
+            }]
+        })
+    );
+    assert_eq!(
+        document(&ArticleError::Conflict),
+        serde_json::json!({
+            "errors": [{
+                "status": "409",
+                "title": "Conflict",
+                "detail": "Conflict",
+                "source": { "parameter": "filter" },
+            }]
+        })
+    );
+    assert_eq!(
+        document(&ArticleError::Internal),
+        serde_json::json!({
+            "errors": [{
+                "status": "500",
+                "title": "Internal Server Error",
+                "detail": "Internal",
+            }]
+        })
+    );
+}
+
+#[test]
+fn field_source() {
+    let error = ArticleError::InvalidAttribute {
+        pointer: "/data/attributes/body".to_owned(),
+    };
+    let body = error.error_response().into_body().try_into_bytes().unwrap();
+    let document: JsonApiDocument = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        document,
+        JsonApiDocument {
+            errors: vec![JsonApiError {
+                status: "422".to_owned(),
+                code: None,
+                title: Some("Unprocessable Entity".to_owned()),
+                detail: "Invalid attribute".to_owned(),
+                source: Some(JsonApiSource::pointer("/data/attributes/body")),
+            }],
+        }
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        document(&InvalidValue("/data/id")),
+        serde_json::json!({
+            "errors": [{
+                "status": "400",
+                "code": "invalid_value",
+                "title": "Bad Request",
+                "detail": "Invalid value /data/id",
+                "source": { "pointer": "/data/id" },
+            }]
+        })
+    );
+}
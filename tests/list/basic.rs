@@ -0,0 +1,101 @@
+use crate::common::expect_response;
+use actix_web::{http::header, http::StatusCode, ResponseError};
+use actix_web_error::{ErrorCode, ErrorList, StatusStrategy};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum FieldError {
+    #[error("{0} is required")]
+    #[error_code("required")]
+    Required(&'static str),
+    #[error("{0} is too long")]
+    TooLong(&'static str),
+    #[error("{0} is taken")]
+    #[status(409)]
+    #[error_code("taken")]
+    Taken(&'static str),
+    #[error("Lookup failed")]
+    #[status(503)]
+    Unavailable,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Rate limited")]
+#[status(429)]
+struct RateLimited;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Name missing")]
+#[status(422)]
+#[error_code("name_missing")]
+struct NameMissing;
+
+fn errors() -> ErrorList<FieldError> {
+    [
+        FieldError::Required("name"),
+        FieldError::TooLong("email"),
+        FieldError::Taken("login"),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn error_code() {
+    assert_eq!(FieldError::Required("").error_code(), Some("required"));
+    assert_eq!(FieldError::TooLong("").error_code(), None);
+    assert_eq!(ErrorCode::error_code(&NameMissing), Some("name_missing"));
+    assert_eq!(ErrorCode::error_code(&RateLimited), None);
+}
+
+#[test]
+fn body() {
+    expect_response(
+        &errors(),
+        StatusCode::CONFLICT,
+        r#"[{"message":"name is required","code":"required"},{"message":"email is too long"},{"message":"login is taken","code":"taken"}]"#,
+    );
+    let res = errors().error_response();
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+}
+
+#[test]
+fn status_strategy() {
+    assert_eq!(errors().status_code(), StatusCode::CONFLICT);
+    assert_eq!(
+        errors().status(StatusStrategy::First).status_code(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        errors()
+            .status(StatusStrategy::Fixed(StatusCode::UNPROCESSABLE_ENTITY))
+            .status_code(),
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let mut errors = errors();
+    errors.push(FieldError::Unavailable);
+    assert_eq!(errors.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let empty = ErrorList::<FieldError>::new();
+    assert_eq!(empty.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn list() {
+    let mut errors = ErrorList::new();
+    assert!(errors.is_empty());
+    errors.extend([RateLimited, RateLimited]);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.to_string(), "Rate limited; Rate limited");
+    expect_response(
+        &errors,
+        StatusCode::TOO_MANY_REQUESTS,
+        r#"[{"message":"Rate limited"},{"message":"Rate limited"}]"#,
+    );
+    assert_eq!(errors.into_result().unwrap_err().into_inner().len(), 2);
+    assert!(ErrorList::<NameMissing>::default().into_result().is_ok());
+}
@@ -0,0 +1 @@
+mod basic;
@@ -0,0 +1,227 @@
+use actix_web_error::ErrorBody;
+use serde_json::json;
+use utoipa::{IntoResponses, PartialSchema};
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum MyEnum {
+    #[error("Missing: {0}")]
+    #[error_code("missing")]
+    MissingField(&'static str),
+    #[error("Malformed Date")]
+    MalformedDate,
+    #[error("Not Found")]
+    #[status(404)]
+    NotFound,
+    #[error(transparent)]
+    #[status(transparent)]
+    Other(MyError),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Item not found")]
+#[status(404)]
+struct MyError;
+
+#[test]
+fn enums() {
+    let error_body = serde_json::to_value(ErrorBody::schema()).unwrap();
+    assert_eq!(
+        serde_json::to_value(MyEnum::responses()).unwrap(),
+        json!({
+            "400": {
+                "description": "Bad Request",
+                "content": {
+                    "application/json": {
+                        "schema": error_body,
+                        "examples": {
+                            "MissingField": { "value": { "message": "Missing: <0>", "code": "missing" } },
+                            "MalformedDate": { "value": { "message": "Malformed Date" } },
+                        },
+                    },
+                },
+            },
+            "404": {
+                "description": "Not Found",
+                "content": {
+                    "application/json": {
+                        "schema": error_body,
+                        "examples": {
+                            "NotFound": { "value": { "message": "Not Found" } },
+                        },
+                    },
+                },
+            },
+            "default": {
+                "description": "Error",
+                "content": {
+                    "application/json": {
+                        "schema": error_body,
+                    },
+                },
+            },
+        })
+    );
+}
+
+#[test]
+fn structs() {
+    assert_eq!(
+        serde_json::to_value(MyError::responses()).unwrap(),
+        json!({
+            "404": {
+                "description": "Not Found",
+                "content": {
+                    "text/plain; charset=utf-8": {
+                        "schema": { "type": "string" },
+                        "examples": {
+                            "MyError": { "value": "Item not found" },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::JsonApi)]
+#[error("Title missing")]
+#[status(422)]
+#[error_code("missing_title")]
+struct MissingTitle;
+
+#[test]
+fn json_api() {
+    let responses = serde_json::to_value(MissingTitle::responses()).unwrap();
+    let content = &responses["422"]["content"]["application/vnd.api+json"];
+    assert_eq!(
+        content["examples"]["MissingTitle"]["value"],
+        json!({
+            "errors": [{
+                "status": "422",
+                "code": "missing_title",
+                "title": "Unprocessable Entity",
+                "detail": "Title missing",
+            }]
+        })
+    );
+    assert_eq!(content["schema"]["required"], json!(["errors"]));
+    assert_eq!(
+        content["schema"]["properties"]["errors"]["items"]["required"],
+        json!(["status", "detail"])
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[status(409)]
+enum ShelfError {
+    #[error("Shelf exists")]
+    #[error_code("shelf_exists")]
+    Exists,
+    #[error("Shelf locked")]
+    #[grpc(code = Aborted)]
+    Locked,
+}
+
+#[test]
+fn google() {
+    let responses = serde_json::to_value(ShelfError::responses()).unwrap();
+    let content = &responses["409"]["content"]["application/json"];
+    assert_eq!(
+        content["examples"]["Exists"]["value"],
+        json!({
+            "error": {
+                "code": 409,
+                "status": "ALREADY_EXISTS",
+                "message": "Shelf exists",
+                "details": [{
+                    "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+                    "reason": "shelf_exists",
+                }],
+            }
+        })
+    );
+    assert_eq!(
+        content["examples"]["Locked"]["value"]["error"]["status"],
+        "ABORTED"
+    );
+    assert_eq!(content["schema"]["required"], json!(["error"]));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(404)]
+enum LookupError {
+    #[error("Book {id:?} not found in {{shelf}}")]
+    Book { id: u32 },
+    #[error("Author {} not found", .0)]
+    Author(String),
+}
+
+#[test]
+fn format_arguments() {
+    let responses = serde_json::to_value(LookupError::responses()).unwrap();
+    let examples = &responses["404"]["content"]["application/json"]["examples"];
+    assert_eq!(
+        examples["Book"]["value"],
+        json!({ "message": "Book <id> not found in {shelf}" })
+    );
+    assert!(examples.get("Author").is_none());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum CartError {
+    #[error("Cart is empty")]
+    #[error_code("empty_cart")]
+    Empty,
+    #[error("Checkout failed")]
+    #[status(transparent)]
+    #[error_code(transparent)]
+    Checkout(MyEnum),
+    #[error("Cart not found")]
+    #[status(404)]
+    NotFound,
+    #[error(transparent)]
+    #[response(transparent)]
+    Item(MyError),
+}
+
+#[test]
+fn transparent_code() {
+    let responses = serde_json::to_value(CartError::responses()).unwrap();
+    assert_eq!(
+        responses["default"]["content"]["application/json"]["examples"]["Checkout"]["value"],
+        json!({ "message": "Checkout failed", "code": "<code>" })
+    );
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error(transparent)]
+#[response(transparent)]
+struct Forwarded(MyError);
+
+#[test]
+fn forwarded() {
+    assert_eq!(
+        serde_json::to_value(Forwarded::responses()).unwrap(),
+        serde_json::to_value(MyError::responses()).unwrap()
+    );
+
+    let responses = serde_json::to_value(CartError::responses()).unwrap();
+    let content = &responses["404"]["content"];
+    assert_eq!(
+        content["application/json"]["examples"]["NotFound"]["value"],
+        json!({ "message": "Cart not found" })
+    );
+    assert_eq!(
+        content["text/plain; charset=utf-8"]["examples"]["MyError"]["value"],
+        "Item not found"
+    );
+    assert!(responses["400"]["content"]["application/json"]["examples"]
+        .get("Item")
+        .is_none());
+}
@@ -0,0 +1,89 @@
+use actix_web::{
+    body::MessageBody,
+    http::{header::HeaderName, StatusCode},
+    test, web, App, HttpResponse,
+};
+use actix_web_error::panic::{CatchPanic, Panic};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Internal Server Error ({0})")]
+#[status(500)]
+struct InternalError(String);
+
+#[allow(clippy::unused_async)]
+async fn panics() -> HttpResponse {
+    panic!("oh no")
+}
+
+async fn ok() -> HttpResponse {
+    HttpResponse::Ok().body("ok")
+}
+
+#[actix_web::test]
+async fn catch_panic() {
+    let app = test::init_service(
+        App::new()
+            .wrap(CatchPanic::new(|panic: Panic| {
+                assert_eq!(panic.message, "oh no");
+                InternalError(panic.correlation_id)
+            }))
+            .route("/panic", web::get().to(panics))
+            .route("/ok", web::get().to(ok)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/panic")
+        .insert_header(("x-request-id", "abc"))
+        .to_request();
+    let res = test::try_call_service(&app, req)
+        .await
+        .unwrap_err()
+        .error_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(res.headers().get("x-request-id").unwrap(), "abc");
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Internal Server Error (abc)"}"#
+    );
+
+    let req = test::TestRequest::get().uri("/panic").to_request();
+    let res = test::try_call_service(&app, req)
+        .await
+        .unwrap_err()
+        .error_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+    assert_eq!(id.len(), 36);
+
+    let res = test::call_service(&app, test::TestRequest::get().uri("/ok").to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_body().try_into_bytes().unwrap(), "ok");
+}
+
+#[actix_web::test]
+async fn correlation_header() {
+    let app = test::init_service(
+        App::new()
+            .wrap(
+                CatchPanic::new(|panic: Panic| InternalError(panic.correlation_id))
+                    .correlation_header(HeaderName::from_static("x-correlation-id")),
+            )
+            .route("/panic", web::get().to(panics)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/panic")
+        .insert_header(("x-correlation-id", "xyz"))
+        .to_request();
+    let res = test::try_call_service(&app, req)
+        .await
+        .unwrap_err()
+        .error_response();
+    assert_eq!(res.headers().get("x-correlation-id").unwrap(), "xyz");
+    assert_eq!(
+        res.into_body().try_into_bytes().unwrap(),
+        r#"{"message":"Internal Server Error (xyz)"}"#
+    );
+}
@@ -0,0 +1,93 @@
+use crate::common::expect_response;
+use actix_web::http::StatusCode;
+#[cfg(all(feature = "anyhow", feature = "io"))]
+use std::io;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Order {0} not found")]
+#[error_code("order_not_found")]
+#[status(NOT_FOUND)]
+struct OrderNotFound(u32);
+
+#[cfg(feature = "anyhow")]
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Order is paid")]
+#[status(CONFLICT)]
+struct OrderPaid;
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow() {
+    use actix_web_error::report::AnyhowResponse;
+
+    actix_web_error::source::register::<OrderNotFound>();
+    actix_web_error::source::register::<OrderPaid>();
+
+    let error = anyhow::Error::new(OrderNotFound(3)).context("cancelling the order");
+    expect_response(
+        &AnyhowResponse(error),
+        StatusCode::NOT_FOUND,
+        r#"{"message":"Order 3 not found","code":"order_not_found"}"#,
+    );
+    expect_response(
+        &AnyhowResponse::from(OrderPaid),
+        StatusCode::CONFLICT,
+        "Order is paid",
+    );
+    #[cfg(feature = "io")]
+    {
+        let error = anyhow::Error::new(io::Error::from(io::ErrorKind::PermissionDenied));
+        expect_response(
+            &AnyhowResponse(error.context("reading the invoice")),
+            StatusCode::FORBIDDEN,
+            r#"{"message":"Forbidden"}"#,
+        );
+    }
+    expect_response(
+        &AnyhowResponse(anyhow::anyhow!("connection to 10.0.0.3 refused")),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        r#"{"message":"Internal Server Error"}"#,
+    );
+}
+
+#[cfg(feature = "eyre")]
+#[test]
+fn eyre() {
+    use actix_web_error::report::EyreResponse;
+    use eyre::WrapErr;
+
+    actix_web_error::source::register::<OrderNotFound>();
+
+    let error = Err::<(), _>(OrderNotFound(5))
+        .wrap_err("cancelling the order")
+        .unwrap_err();
+    expect_response(
+        &EyreResponse(error),
+        StatusCode::NOT_FOUND,
+        r#"{"message":"Order 5 not found","code":"order_not_found"}"#,
+    );
+    expect_response(
+        &EyreResponse(eyre::eyre!("disk full")),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        r#"{"message":"Internal Server Error"}"#,
+    );
+}
+
+#[cfg(feature = "anyhow")]
+#[actix_web::test]
+async fn handler() {
+    use actix_web::{web, App};
+    use actix_web_error::report::AnyhowResponse;
+
+    async fn cancel() -> Result<&'static str, AnyhowResponse> {
+        Err(anyhow::Error::new(OrderPaid).context("cancelling the order"))?
+    }
+
+    actix_web_error::source::register::<OrderPaid>();
+
+    let app = actix_web::test::init_service(App::new().route("/", web::post().to(cancel))).await;
+    let req = actix_web::test::TestRequest::post().to_request();
+    let res = actix_web::test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+    assert_eq!(actix_web::test::read_body(res).await, "Order is paid");
+}
@@ -0,0 +1,201 @@
+use schemars::{JsonSchema, SchemaGenerator};
+use serde_json::json;
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum MyEnum {
+    #[error("Missing: {0}")]
+    #[error_code("missing")]
+    MissingField(&'static str),
+    #[error("Malformed Date")]
+    #[error_code("malformed_date")]
+    MalformedDate,
+    #[error("Malformed Time")]
+    #[error_code("malformed_date")]
+    MalformedTime,
+    #[error("Internal")]
+    #[status(500)]
+    Internal,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Item not found")]
+#[status(404)]
+struct MyError;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Item not found")]
+#[status(404)]
+struct MyTextError;
+
+fn schema<T: JsonSchema>() -> serde_json::Value {
+    T::json_schema(&mut SchemaGenerator::default()).to_value()
+}
+
+#[test]
+fn codes() {
+    assert_eq!(MyEnum::schema_name(), "MyEnum");
+    assert_eq!(
+        schema::<MyEnum>(),
+        json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" },
+                "code": { "type": "string", "enum": ["missing", "malformed_date"] },
+            },
+            "required": ["message"],
+            "additionalProperties": false,
+        })
+    );
+}
+
+#[test]
+fn no_codes() {
+    assert_eq!(
+        schema::<MyError>(),
+        json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" },
+            },
+            "required": ["message"],
+            "additionalProperties": false,
+        })
+    );
+    assert_eq!(schema::<MyTextError>(), json!({ "type": "string" }));
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::JsonApi)]
+#[error("Title missing")]
+#[status(422)]
+#[error_code("missing_title")]
+#[json_api(pointer = "/data/attributes/title")]
+struct MissingTitle;
+
+#[test]
+fn json_api() {
+    assert_eq!(
+        schema::<MissingTitle>(),
+        json!({
+            "type": "object",
+            "properties": {
+                "errors": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "status": { "type": "string" },
+                            "code": { "type": "string", "enum": ["missing_title"] },
+                            "title": { "type": "string" },
+                            "detail": { "type": "string" },
+                            "source": {
+                                "type": "object",
+                                "properties": {
+                                    "pointer": { "type": "string" },
+                                    "parameter": { "type": "string" },
+                                },
+                                "additionalProperties": false,
+                            },
+                        },
+                        "required": ["status", "detail"],
+                        "additionalProperties": false,
+                    },
+                },
+            },
+            "required": ["errors"],
+        })
+    );
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::GoogleJson)]
+#[error("Book not found")]
+#[status(404)]
+#[error_code("book_not_found")]
+struct BookNotFound;
+
+#[test]
+fn google() {
+    let schema = schema::<BookNotFound>();
+    let error = &schema["properties"]["error"];
+    assert_eq!(schema["required"], json!(["error"]));
+    assert_eq!(error["required"], json!(["code", "status", "message"]));
+    assert_eq!(error["properties"]["status"]["enum"][5], "NOT_FOUND");
+    assert_eq!(
+        error["properties"]["details"]["items"]["properties"]["reason"],
+        json!({ "type": "string", "enum": ["book_not_found"] })
+    );
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Checkout failed")]
+#[status(transparent)]
+#[error_code(transparent)]
+struct CheckoutError(MyEnum);
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum CartError {
+    #[error("Cart is empty")]
+    #[error_code("empty_cart")]
+    Empty,
+    #[error("Checkout failed")]
+    #[status(transparent)]
+    #[error_code(transparent)]
+    Checkout(MyEnum),
+}
+
+#[test]
+fn transparent_code() {
+    let any_code = json!({
+        "type": "object",
+        "properties": {
+            "message": { "type": "string" },
+            "code": { "type": "string" },
+        },
+        "required": ["message"],
+        "additionalProperties": false,
+    });
+    assert_eq!(schema::<CheckoutError>(), any_code);
+    assert_eq!(schema::<CartError>(), any_code);
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error(transparent)]
+#[response(transparent)]
+struct Forwarded(MyTextError);
+
+#[allow(dead_code)]
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum OrderError {
+    #[error("Order is empty")]
+    #[error_code("empty_order")]
+    Empty,
+    #[error(transparent)]
+    #[response(transparent)]
+    Item(MyTextError),
+}
+
+#[test]
+fn forwarded() {
+    assert_eq!(schema::<Forwarded>(), json!({ "type": "string" }));
+    assert_eq!(
+        schema::<OrderError>(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "message": { "type": "string" },
+                        "code": { "type": "string", "enum": ["empty_order"] },
+                    },
+                    "required": ["message"],
+                    "additionalProperties": false,
+                },
+                { "type": "string" },
+            ],
+        })
+    );
+}
@@ -0,0 +1,152 @@
+use crate::common::expect_response;
+use actix_web::{http::StatusCode, ResponseError};
+use actix_web_error::source;
+use std::error::Error;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Account is locked")]
+#[status(LOCKED)]
+struct Locked;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Lookup failed")]
+struct LookupFailed(#[source] Locked);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Request failed")]
+#[error_code("request")]
+#[status(source)]
+struct RequestError(BoxError);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_GATEWAY)]
+enum UpstreamError {
+    #[error("Upstream {name} failed")]
+    #[status(source)]
+    Call {
+        name: &'static str,
+        #[source]
+        source: BoxError,
+    },
+    #[cfg(feature = "json")]
+    #[error("Upstream returned garbage")]
+    #[status(source)]
+    Decode(u32, #[source] serde_json::Error),
+    #[error("Upstream unavailable")]
+    Unavailable,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Generic failure")]
+#[status(source)]
+struct GenericError<E: Error + 'static>(#[source] E);
+
+#[test]
+fn registered() {
+    source::register::<Locked>();
+
+    expect_response(
+        &RequestError(Box::new(Locked)),
+        StatusCode::LOCKED,
+        r#"{"message":"Request failed","code":"request"}"#,
+    );
+    assert_eq!(
+        RequestError(Box::new(LookupFailed(Locked))).status_code(),
+        StatusCode::LOCKED
+    );
+    assert_eq!(
+        GenericError(LookupFailed(Locked)).status_code(),
+        StatusCode::LOCKED
+    );
+}
+
+#[test]
+fn register_with() {
+    source::register_with::<std::num::ParseIntError>(|_| StatusCode::BAD_REQUEST);
+
+    let error = "x".parse::<u8>().unwrap_err();
+    assert_eq!(
+        RequestError(Box::new(error)).status_code(),
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[test]
+fn register_again() {
+    source::register_with::<std::num::ParseFloatError>(|_| StatusCode::PAYLOAD_TOO_LARGE);
+    source::register_with::<std::num::ParseFloatError>(|_| StatusCode::BAD_REQUEST);
+
+    let error = "x".parse::<f32>().unwrap_err();
+    assert_eq!(
+        RequestError(Box::new(error)).status_code(),
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Retry failed")]
+struct RetryFailed(#[source] BoxError);
+
+#[test]
+fn reentrant() {
+    // the status of these errors is looked up in the registry as well
+    source::register::<Locked>();
+    source::register::<RequestError>();
+    source::register_with::<RetryFailed>(|error| {
+        source::status_of(&*error.0).unwrap_or(StatusCode::SERVICE_UNAVAILABLE)
+    });
+
+    let error = RetryFailed(Box::new(RequestError(Box::new(Locked))));
+    assert_eq!(
+        RequestError(Box::new(error)).status_code(),
+        StatusCode::LOCKED
+    );
+    let error = RetryFailed("unknown".into());
+    assert_eq!(
+        RequestError(Box::new(error)).status_code(),
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+#[cfg(feature = "io")]
+#[test]
+fn builtin_io() {
+    use std::io;
+
+    assert_eq!(
+        RequestError(Box::new(io::Error::from(io::ErrorKind::NotFound))).status_code(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn builtin_json() {
+    let error = serde_json::from_str::<u32>("[").unwrap_err();
+    assert_eq!(
+        UpstreamError::Decode(7, error).status_code(),
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[test]
+fn fallback() {
+    assert_eq!(
+        RequestError("unknown".into()).status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    expect_response(
+        &UpstreamError::Call {
+            name: "billing",
+            source: "unknown".into(),
+        },
+        StatusCode::BAD_GATEWAY,
+        r#"{"message":"Upstream billing failed"}"#,
+    );
+    assert_eq!(
+        UpstreamError::Unavailable.status_code(),
+        StatusCode::BAD_GATEWAY
+    );
+}
@@ -0,0 +1,100 @@
+use actix_web::{
+    http::{header, StatusCode},
+    web::{self, Bytes},
+    App, HttpResponse,
+};
+use actix_web_error::sse::{error_frame, with_error_frames};
+use futures_util::stream::{self, StreamExt};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(SERVICE_UNAVAILABLE)]
+enum FeedError {
+    #[error("Feed closed")]
+    #[error_code("feed_closed")]
+    Closed,
+    #[error("Lagging behind by {0} events")]
+    Lagging(usize),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("first line\nsecond line")]
+#[status(500)]
+struct Multiline;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Html)]
+#[error("")]
+#[status(500)]
+#[error_code("empty")]
+struct Empty;
+
+#[test]
+fn json_frame() {
+    assert_eq!(
+        error_frame(&FeedError::Closed),
+        "event: error\ndata: {\"message\":\"Feed closed\",\"code\":\"feed_closed\"}\n\n"
+    );
+    assert_eq!(
+        error_frame(&FeedError::Lagging(3)),
+        "event: error\ndata: {\"message\":\"Lagging behind by 3 events\"}\n\n"
+    );
+}
+
+#[test]
+fn other_derives() {
+    // the data is the JSON body, whichever body the derive responds with
+    assert_eq!(
+        error_frame(&Multiline),
+        "event: error\ndata: {\"message\":\"first line\\nsecond line\"}\n\n"
+    );
+    assert_eq!(
+        error_frame(&Empty),
+        "event: error\ndata: {\"message\":\"\",\"code\":\"empty\"}\n\n"
+    );
+}
+
+#[actix_web::test]
+async fn stream_ends_after_error() {
+    let events = stream::iter([
+        Ok(Bytes::from_static(b"data: 1\n\n")),
+        Err(FeedError::Closed),
+        Ok(Bytes::from_static(b"data: 2\n\n")),
+    ]);
+    let frames: Vec<_> = with_error_frames(events)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(
+        frames,
+        [
+            Bytes::from_static(b"data: 1\n\n"),
+            error_frame(&FeedError::Closed),
+        ]
+    );
+}
+
+#[actix_web::test]
+async fn streaming_response() {
+    async fn feed() -> HttpResponse {
+        let events = stream::iter([
+            Ok(Bytes::from_static(b"data: 1\n\n")),
+            Err(FeedError::Lagging(7)),
+        ]);
+        HttpResponse::Ok()
+            .content_type("text/event-stream")
+            .streaming(with_error_frames(events))
+    }
+
+    let app = actix_web::test::init_service(App::new().route("/", web::get().to(feed))).await;
+    let res =
+        actix_web::test::call_service(&app, actix_web::test::TestRequest::get().to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    let body = actix_web::body::to_bytes(res.into_body()).await.unwrap();
+    assert_eq!(
+        body,
+        "data: 1\n\nevent: error\ndata: {\"message\":\"Lagging behind by 7 events\"}\n\n"
+    );
+}
@@ -0,0 +1,3 @@
+#![cfg(any(feature = "msgpack", feature = "cbor"))]
+
+mod binary;
@@ -0,0 +1,3 @@
+#![cfg(feature = "async-graphql")]
+
+mod graphql;
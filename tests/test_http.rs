@@ -0,0 +1,2 @@
+#![cfg(feature = "http")]
+mod http_response;
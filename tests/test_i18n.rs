@@ -0,0 +1 @@
+mod i18n;
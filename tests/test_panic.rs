@@ -0,0 +1,3 @@
+#![cfg(feature = "panic")]
+
+mod panic;
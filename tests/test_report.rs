@@ -0,0 +1,4 @@
+#![cfg(any(feature = "anyhow", feature = "eyre"))]
+
+mod common;
+mod report;
@@ -0,0 +1,2 @@
+#![cfg(feature = "schemars")]
+mod schema;
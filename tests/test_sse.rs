@@ -0,0 +1 @@
+mod sse;
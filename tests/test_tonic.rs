@@ -0,0 +1,3 @@
+#![cfg(feature = "tonic")]
+
+mod grpc;
@@ -0,0 +1,2 @@
+#![cfg(feature = "utoipa")]
+mod openapi;
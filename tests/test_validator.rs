@@ -0,0 +1,4 @@
+#![cfg(feature = "validator")]
+
+mod common;
+mod validation;
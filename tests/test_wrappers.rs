@@ -0,0 +1,7 @@
+#![cfg(any(
+    all(feature = "io", feature = "json", feature = "timeout"),
+    feature = "sqlx"
+))]
+
+mod common;
+mod wrappers;
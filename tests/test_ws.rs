@@ -0,0 +1,3 @@
+#![cfg(feature = "ws")]
+
+mod ws;
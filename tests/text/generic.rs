@@ -14,7 +14,11 @@ struct MyStruct;
 
 impl MyTrait for MyStruct {}
 
-impl ResponseError for MyStruct {}
+impl ResponseError for MyStruct {
+    fn status_code(&self) -> StatusCode {
+        Self::status()
+    }
+}
 
 #[derive(Debug, thiserror::Error, actix_web_error::Text)]
 #[error("Error: {0}")]
@@ -0,0 +1,148 @@
+use crate::common::expect_response;
+use actix_web::{http::StatusCode, ResponseError};
+use actix_web_error::ErrorCode;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+enum PaymentError {
+    #[error("Card declined")]
+    #[error_code("card_declined")]
+    #[status(PAYMENT_REQUIRED)]
+    Declined,
+    #[error("Card expired")]
+    #[error_code("card_expired")]
+    #[status(BAD_REQUEST)]
+    Expired,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Try again later")]
+#[status(SERVICE_UNAVAILABLE)]
+struct Unavailable;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Checkout failed")]
+#[status(transparent)]
+#[error_code(transparent)]
+struct CheckoutError(#[from] PaymentError);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error(transparent)]
+#[response(transparent)]
+struct Forwarded(#[from] PaymentError);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum OrderError {
+    #[error("Payment failed")]
+    #[status(transparent)]
+    #[error_code(transparent)]
+    Payment(PaymentError),
+    #[error("Checkout is unavailable")]
+    #[response(transparent)]
+    Unavailable { inner: Unavailable },
+    #[error("Cart is empty")]
+    #[error_code("empty_cart")]
+    Empty,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[status(BAD_GATEWAY)]
+enum ProxyError {
+    #[error(transparent)]
+    #[response(transparent)]
+    Payment(PaymentError),
+    #[error("Upstream failed")]
+    Upstream,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error(transparent)]
+enum OnlyForwarded {
+    #[response(transparent)]
+    Payment(PaymentError),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Wrapped")]
+#[response(transparent)]
+struct Wrapper<E: std::error::Error>(E);
+
+#[test]
+fn error_code() {
+    expect_response(
+        &CheckoutError(PaymentError::Declined),
+        StatusCode::PAYMENT_REQUIRED,
+        r#"{"message":"Checkout failed","code":"card_declined"}"#,
+    );
+    assert_eq!(
+        CheckoutError(PaymentError::Expired).error_code(),
+        Some("card_expired")
+    );
+    expect_response(
+        &OrderError::Payment(PaymentError::Expired),
+        StatusCode::BAD_REQUEST,
+        r#"{"message":"Payment failed","code":"card_expired"}"#,
+    );
+}
+
+#[test]
+fn response() {
+    expect_response(
+        &Forwarded(PaymentError::Declined),
+        StatusCode::PAYMENT_REQUIRED,
+        r#"{"message":"Card declined","code":"card_declined"}"#,
+    );
+    assert_eq!(
+        Forwarded(PaymentError::Expired).error_code(),
+        Some("card_expired")
+    );
+
+    let error = OrderError::Unavailable { inner: Unavailable };
+    expect_response(&error, StatusCode::SERVICE_UNAVAILABLE, "Try again later");
+    assert_eq!(
+        error
+            .error_response()
+            .headers()
+            .get(actix_web::http::header::CONTENT_TYPE)
+            .unwrap(),
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(error.error_code(), None);
+    expect_response(
+        &OrderError::Empty,
+        StatusCode::BAD_REQUEST,
+        r#"{"message":"Cart is empty","code":"empty_cart"}"#,
+    );
+}
+
+#[test]
+fn other_derives() {
+    expect_response(
+        &ProxyError::Payment(PaymentError::Declined),
+        StatusCode::PAYMENT_REQUIRED,
+        r#"{"message":"Card declined","code":"card_declined"}"#,
+    );
+    expect_response(
+        &ProxyError::Upstream,
+        StatusCode::BAD_GATEWAY,
+        "Upstream failed",
+    );
+    expect_response(
+        &OnlyForwarded::Payment(PaymentError::Expired),
+        StatusCode::BAD_REQUEST,
+        r#"{"message":"Card expired","code":"card_expired"}"#,
+    );
+}
+
+#[test]
+fn generic() {
+    expect_response(
+        &Wrapper(PaymentError::Expired),
+        StatusCode::BAD_REQUEST,
+        r#"{"message":"Card expired","code":"card_expired"}"#,
+    );
+    assert_eq!(
+        Wrapper(Unavailable).status_code(),
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+}
@@ -0,0 +1,132 @@
+use crate::common::expect_response;
+use actix_web::{http::header, http::StatusCode, ResponseError};
+use actix_web_error::validation::{ValidationDetail, ValidationFailed};
+use validator::{Validate, ValidationError};
+
+#[derive(Validate)]
+struct Address {
+    #[validate(length(max = 5, message = "City is too long"))]
+    city: String,
+}
+
+#[derive(Validate)]
+struct Tag {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Validate)]
+#[validate(schema(function = "validate_order"))]
+struct Order {
+    #[validate(range(min = 1))]
+    quantity: u32,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    tags: Vec<Tag>,
+}
+
+fn validate_order(order: &Order) -> Result<(), ValidationError> {
+    if order.quantity > 10 && order.tags.is_empty() {
+        return Err(ValidationError::new("bulk_untagged"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum OrderError {
+    #[error(transparent)]
+    #[status(transparent)]
+    Invalid(#[from] ValidationFailed),
+    #[error("Out of stock")]
+    OutOfStock,
+}
+
+fn invalid_order() -> ValidationFailed {
+    Order {
+        quantity: 0,
+        address: Address {
+            city: "Springfield".to_owned(),
+        },
+        tags: vec![
+            Tag {
+                name: "gift".to_owned(),
+            },
+            Tag {
+                name: String::new(),
+            },
+        ],
+    }
+    .validate()
+    .unwrap_err()
+    .into()
+}
+
+#[test]
+fn details() {
+    assert_eq!(
+        invalid_order().details(),
+        [
+            ValidationDetail {
+                pointer: "/address/city".to_owned(),
+                code: "length".to_owned(),
+                message: Some("City is too long".to_owned()),
+            },
+            ValidationDetail {
+                pointer: "/quantity".to_owned(),
+                code: "range".to_owned(),
+                message: None,
+            },
+            ValidationDetail {
+                pointer: "/tags/1/name".to_owned(),
+                code: "length".to_owned(),
+                message: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn struct_level() {
+    let error = ValidationFailed::from(
+        Order {
+            quantity: 20,
+            address: Address {
+                city: "Rome".to_owned(),
+            },
+            tags: Vec::new(),
+        }
+        .validate()
+        .unwrap_err(),
+    );
+    expect_response(
+        &error,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        r#"{"message":"Validation failed","code":"validation_failed","details":[{"pointer":"","code":"bulk_untagged"}]}"#,
+    );
+}
+
+#[test]
+fn response() {
+    expect_response(
+        &invalid_order(),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        r#"{"message":"Validation failed","code":"validation_failed","details":[{"pointer":"/address/city","code":"length","message":"City is too long"},{"pointer":"/quantity","code":"range"},{"pointer":"/tags/1/name","code":"length"}]}"#,
+    );
+    let res = invalid_order().error_response();
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+}
+
+#[test]
+fn transparent() {
+    let error = OrderError::from(invalid_order());
+    assert_eq!(error.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        OrderError::OutOfStock.status_code(),
+        StatusCode::BAD_REQUEST
+    );
+}
@@ -0,0 +1,143 @@
+use crate::common::expect_response;
+use actix_web::{http::StatusCode, ResponseError};
+use actix_web_error::wrappers::{IoError, JsonError, Timeout};
+use std::io;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum UploadError {
+    #[error("Storage failed")]
+    #[error_code("storage")]
+    #[status(transparent)]
+    Storage(#[from] IoError),
+    #[error("Malformed metadata")]
+    #[status(transparent)]
+    Metadata(#[from] JsonError),
+    #[error("Upload timed out")]
+    #[status(transparent)]
+    Timeout(#[from] Timeout),
+    #[error("Empty upload")]
+    Empty,
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+enum ExportError {
+    #[error("Can't write the export")]
+    #[status(transparent)]
+    #[error_code(transparent)]
+    Io(IoError),
+    #[error(transparent)]
+    #[response(transparent)]
+    Timeout(Timeout),
+}
+
+#[test]
+fn io() {
+    for (kind, status) in [
+        (io::ErrorKind::NotFound, StatusCode::NOT_FOUND),
+        (io::ErrorKind::PermissionDenied, StatusCode::FORBIDDEN),
+        (io::ErrorKind::AlreadyExists, StatusCode::CONFLICT),
+        (io::ErrorKind::InvalidData, StatusCode::BAD_REQUEST),
+        (io::ErrorKind::TimedOut, StatusCode::GATEWAY_TIMEOUT),
+        (io::ErrorKind::BrokenPipe, StatusCode::INTERNAL_SERVER_ERROR),
+    ] {
+        assert_eq!(IoError(kind.into()).status_code(), status, "{kind:?}");
+    }
+}
+
+#[test]
+fn json() {
+    let error = serde_json::from_str::<u32>("{").unwrap_err();
+    assert_eq!(JsonError(error).status_code(), StatusCode::BAD_REQUEST);
+    let error = serde_json::from_str::<u32>("\"x\"").unwrap_err();
+    assert_eq!(JsonError(error).status_code(), StatusCode::BAD_REQUEST);
+    let error = serde_json::from_reader::<_, u32>(FailingReader).unwrap_err();
+    assert_eq!(
+        JsonError(error).status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+struct FailingReader;
+
+impl io::Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::ErrorKind::BrokenPipe.into())
+    }
+}
+
+#[test]
+fn masked_server_errors() {
+    expect_response(
+        &IoError(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "/etc/secret: permission denied",
+        )),
+        StatusCode::FORBIDDEN,
+        "/etc/secret: permission denied",
+    );
+    expect_response(
+        &IoError(io::Error::other("/var/lib/app/db: disk full")),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Internal Server Error",
+    );
+    expect_response(&Timeout, StatusCode::GATEWAY_TIMEOUT, "Gateway Timeout");
+}
+
+#[test]
+fn transparent() {
+    expect_response(
+        &UploadError::from(IoError(io::ErrorKind::NotFound.into())),
+        StatusCode::NOT_FOUND,
+        r#"{"message":"Storage failed","code":"storage"}"#,
+    );
+    let error = serde_json::from_str::<u32>("[").unwrap_err();
+    assert_eq!(
+        UploadError::from(JsonError(error)).status_code(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        UploadError::from(Timeout).status_code(),
+        StatusCode::GATEWAY_TIMEOUT
+    );
+    assert_eq!(UploadError::Empty.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[cfg(feature = "tokio")]
+#[actix_web::test]
+async fn tokio_timeout() {
+    let elapsed = tokio::time::timeout(
+        std::time::Duration::from_millis(1),
+        std::future::pending::<()>(),
+    )
+    .await
+    .unwrap_err();
+    let error = UploadError::from(Timeout::from(elapsed));
+    assert_eq!(error.status_code(), StatusCode::GATEWAY_TIMEOUT);
+}
+
+#[test]
+fn transparent_code() {
+    let error = ExportError::Io(IoError(io::ErrorKind::PermissionDenied.into()));
+    assert_eq!(error.error_code(), None);
+    expect_response(
+        &error,
+        StatusCode::FORBIDDEN,
+        r#"{"message":"Can't write the export"}"#,
+    );
+    let error = ExportError::Timeout(Timeout);
+    assert_eq!(error.error_code(), None);
+    expect_response(&error, StatusCode::GATEWAY_TIMEOUT, "Gateway Timeout");
+}
+
+#[cfg(feature = "utoipa")]
+#[test]
+fn forwarded_responses() {
+    use utoipa::IntoResponses;
+
+    let responses = serde_json::to_value(ExportError::responses()).unwrap();
+    assert_eq!(
+        responses["504"]["content"]["text/plain; charset=utf-8"]["schema"],
+        serde_json::json!({ "type": "string" })
+    );
+}
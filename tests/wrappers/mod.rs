@@ -0,0 +1,4 @@
+#[cfg(all(feature = "io", feature = "json", feature = "timeout"))]
+mod basic;
+#[cfg(feature = "sqlx")]
+mod sqlx;
@@ -0,0 +1,36 @@
+use crate::common::expect_response;
+use actix_web::{http::StatusCode, ResponseError};
+use actix_web_error::{wrappers::SqlxError, ErrorCode};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Item not found")]
+#[status(transparent)]
+#[error_code(transparent)]
+struct ItemError(#[from] SqlxError);
+
+#[test]
+fn sqlx() {
+    assert_eq!(
+        SqlxError(sqlx::Error::RowNotFound).status_code(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        SqlxError(sqlx::Error::PoolTimedOut).status_code(),
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+    assert_eq!(
+        SqlxError(sqlx::Error::PoolClosed).status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn transparent_code() {
+    let error = ItemError::from(SqlxError(sqlx::Error::RowNotFound));
+    assert_eq!(error.error_code(), None);
+    expect_response(
+        &error,
+        StatusCode::NOT_FOUND,
+        r#"{"message":"Item not found"}"#,
+    );
+}
@@ -0,0 +1,84 @@
+use actix_web::ResponseError;
+use actix_web_error::ws::{CloseCode, WsClose, MAX_DESCRIPTION_LEN};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[error("Session expired")]
+#[status(401)]
+#[ws_close(4001)]
+struct SessionExpired;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Text)]
+#[error("Room is gone")]
+#[status(410)]
+struct RoomGone;
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(BAD_REQUEST)]
+enum ChatError {
+    #[error("Message too long: {0}")]
+    TooLong(String),
+    #[error("Rate limited")]
+    #[status(429)]
+    #[ws_close(4029)]
+    RateLimited,
+    #[error("Storage failed")]
+    #[status(500)]
+    Storage,
+    #[error("Redirected")]
+    #[status(307)]
+    Redirected,
+    #[error(transparent)]
+    #[status(transparent)]
+    Session(#[from] SessionExpired),
+}
+
+#[derive(Debug, thiserror::Error, actix_web_error::Json)]
+#[status(503)]
+#[ws_close(1013)]
+enum UpstreamError {
+    #[error("Try again later")]
+    Overloaded,
+    #[error("Going away")]
+    #[ws_close(1001)]
+    Shutdown,
+}
+
+#[test]
+fn close_code() {
+    assert_eq!(SessionExpired.close_code(), CloseCode::Other(4001));
+    assert_eq!(RoomGone.close_code(), CloseCode::Policy);
+    assert_eq!(
+        ChatError::TooLong(String::new()).close_code(),
+        CloseCode::Policy
+    );
+    assert_eq!(ChatError::RateLimited.close_code(), CloseCode::Other(4029));
+    assert_eq!(ChatError::Storage.close_code(), CloseCode::Error);
+    assert_eq!(ChatError::Redirected.close_code(), CloseCode::Error);
+    // transparent variants use the close code of the inner error
+    let error = ChatError::Session(SessionExpired);
+    assert_eq!(error.status_code().as_u16(), 401);
+    assert_eq!(error.close_code(), CloseCode::Other(4001));
+}
+
+#[test]
+fn container_close_code() {
+    assert_eq!(UpstreamError::Overloaded.close_code(), CloseCode::Again);
+    assert_eq!(UpstreamError::Shutdown.close_code(), CloseCode::Away);
+}
+
+#[test]
+fn close_reason() {
+    let reason = SessionExpired.close_reason();
+    assert_eq!(reason.code, CloseCode::Other(4001));
+    assert_eq!(reason.description.as_deref(), Some("Session expired"));
+}
+
+#[test]
+fn truncated_description() {
+    // 'ä' is two bytes, so the limit falls into the middle of a character
+    let reason = ChatError::TooLong("ä".repeat(100)).close_reason();
+    let description = reason.description.unwrap();
+    assert!(description.len() <= MAX_DESCRIPTION_LEN);
+    assert_eq!(description.len(), MAX_DESCRIPTION_LEN - 1);
+    assert!(description.starts_with("Message too long: ää"));
+}
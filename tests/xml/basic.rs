@@ -0,0 +1,54 @@
+use crate::common::expect_response;
+use actix_web::{http::header, http::StatusCode, ResponseError};
+
+#[derive(Debug, thiserror::Error, actix_web_error::Xml)]
+#[error("Order {0} not found")]
+#[status(NOT_FOUND)]
+#[error_code("order_not_found")]
+struct OrderNotFound(u32);
+
+#[derive(Debug, thiserror::Error, actix_web_error::Xml)]
+#[status(400)]
+enum PartnerError {
+    #[error("Invalid <{0}> & \"{1}\"")]
+    #[error_code("invalid_field")]
+    InvalidField(&'static str, &'static str),
+    #[error("Unexpected\u{0}character")]
+    Unexpected,
+    #[error("Upstream failed")]
+    #[status(BAD_GATEWAY)]
+    Upstream,
+}
+
+#[test]
+fn basic() {
+    expect_response(
+        &OrderNotFound(7),
+        StatusCode::NOT_FOUND,
+        "<error><message>Order 7 not found</message><code>order_not_found</code></error>",
+    );
+    expect_response(
+        &PartnerError::InvalidField("name", "it's"),
+        StatusCode::BAD_REQUEST,
+        "<error><message>Invalid &lt;name&gt; &amp; &quot;it&apos;s&quot;</message><code>invalid_field</code></error>",
+    );
+    expect_response(
+        &PartnerError::Unexpected,
+        StatusCode::BAD_REQUEST,
+        "<error><message>Unexpected\u{FFFD}character</message></error>",
+    );
+    expect_response(
+        &PartnerError::Upstream,
+        StatusCode::BAD_GATEWAY,
+        "<error><message>Upstream failed</message></error>",
+    );
+}
+
+#[test]
+fn content_type() {
+    let res = OrderNotFound(1).error_response();
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/xml"
+    );
+}